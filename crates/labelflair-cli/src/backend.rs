@@ -0,0 +1,166 @@
+//! Backends that host the labels
+//!
+//! Labels can be synchronized with repositories on GitHub, with projects and groups on GitLab, and
+//! with repositories and organizations on Gitea and Forgejo. Each platform is implemented as a
+//! [`Backend`], so that the commands can plan and apply changes without knowing which platform they
+//! are talking to. The arguments that select the backend are shared between the commands in
+//! [`TargetArgs`].
+
+use clawless::prelude::*;
+use labelflair::label::{Label, LabelName};
+use percent_encoding::{AsciiSet, CONTROLS};
+
+use crate::error::{BackendError, Error};
+use crate::gitea::{Gitea, Owner};
+use crate::github::GitHub;
+use crate::gitlab::{GitLab, Namespace};
+
+/// The number of labels that are requested per page
+pub const PER_PAGE: usize = 100;
+
+/// The characters that must be percent-encoded in a path segment
+///
+/// See https://url.spec.whatwg.org/#path-percent-encode-set for the specification.
+pub const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The fields of a label that only some platforms support
+///
+/// The plan compares every field of a label, so a field that a platform cannot store would show up
+/// as a change on every run. The desired labels are therefore stripped of the fields that the
+/// target does not support before they are compared with the existing labels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Features {
+    /// Whether labels can be exclusive
+    pub exclusive: bool,
+
+    /// Whether labels can have a priority
+    pub priority: bool,
+}
+
+/// A platform that hosts labels
+///
+/// Backends identify labels by their name, which is how the plan refers to them. Implementations
+/// that need other identifiers must look them up themselves.
+pub trait Backend {
+    /// Returns the fields of a label that the platform supports
+    fn features(&self) -> Features;
+
+    /// List all labels
+    fn labels(&self) -> Result<Vec<Label>, BackendError>;
+
+    /// Create a new label
+    fn create_label(&self, label: &Label) -> Result<(), BackendError>;
+
+    /// Update the label with the given name
+    ///
+    /// The label is identified by its current name, which makes it possible to rename the label by
+    /// passing a label with a different name.
+    fn update_label(&self, name: &LabelName, label: &Label) -> Result<(), BackendError>;
+
+    /// Delete the label with the given name
+    fn delete_label(&self, name: &LabelName) -> Result<(), BackendError>;
+}
+
+/// The arguments that select the backend
+///
+/// Exactly one repository, project, group, or organization must be set before the backend can be
+/// used. The arguments that select it are part of the `target` group, which other arguments can
+/// conflict with.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+pub struct TargetArgs {
+    /// The GitHub repository in the format `owner/name`
+    #[clap(short, long, group = "target")]
+    repo: Option<String>,
+    /// The ID or path of a GitLab project, e.g. `group/project`
+    #[clap(long, group = "target")]
+    gitlab_project: Option<String>,
+    /// The ID or path of a GitLab group, e.g. `group/subgroup`
+    #[clap(long, group = "target")]
+    gitlab_group: Option<String>,
+    /// The Gitea or Forgejo repository in the format `owner/name`
+    #[clap(long, group = "target")]
+    gitea_repo: Option<String>,
+    /// The name of a Gitea or Forgejo organization
+    #[clap(long, group = "target")]
+    gitea_org: Option<String>,
+    /// The base URL of the API [default: https://api.github.com for GitHub,
+    /// https://gitlab.com/api/v4 for GitLab, or https://gitea.com/api/v1 for Gitea]
+    #[clap(long)]
+    api_url: Option<String>,
+}
+
+impl Features {
+    /// Remove the fields that are not supported from a label
+    pub fn apply(self, label: &Label) -> Label {
+        let mut label = label.clone();
+
+        if !self.priority {
+            label = label.without_priority();
+        }
+        if !self.exclusive {
+            label = label.without_exclusive();
+        }
+
+        label
+    }
+}
+
+impl TargetArgs {
+    /// Connect to the selected backend
+    ///
+    /// The token for the backend is read from its environment variable.
+    pub fn connect(self) -> Result<Box<dyn Backend>, Error> {
+        if let Some(repository) = self.repo {
+            let api_url = self
+                .api_url
+                .unwrap_or_else(|| crate::github::DEFAULT_API_URL.into());
+            let token = token(crate::github::TOKEN_VARIABLE)?;
+
+            return Ok(Box::new(GitHub::new(api_url, repository, token)));
+        }
+
+        let owner = match (self.gitea_repo, self.gitea_org) {
+            (Some(repository), _) => Some(Owner::Repository(repository)),
+            (None, Some(organization)) => Some(Owner::Organization(organization)),
+            (None, None) => None,
+        };
+
+        if let Some(owner) = owner {
+            let api_url = self
+                .api_url
+                .unwrap_or_else(|| crate::gitea::DEFAULT_API_URL.into());
+            let token = token(crate::gitea::TOKEN_VARIABLE)?;
+
+            return Ok(Box::new(Gitea::new(api_url, owner, token)));
+        }
+
+        let namespace = match (self.gitlab_project, self.gitlab_group) {
+            (Some(project), _) => Namespace::Project(project),
+            (None, Some(group)) => Namespace::Group(group),
+            (None, None) => return Err(Error::MissingTarget),
+        };
+
+        let api_url = self
+            .api_url
+            .unwrap_or_else(|| crate::gitlab::DEFAULT_API_URL.into());
+        let token = token(crate::gitlab::TOKEN_VARIABLE)?;
+
+        Ok(Box::new(GitLab::new(api_url, namespace, token)))
+    }
+}
+
+/// Read the token from the given environment variable
+fn token(variable: &'static str) -> Result<String, Error> {
+    std::env::var(variable).map_err(|_| Error::MissingEnv(variable))
+}
@@ -2,6 +2,13 @@
 //!
 //! This module defines the commands available in the Labelflair CLI.
 
+mod docs;
 mod generate;
+mod list;
+mod migrate;
+mod plan;
+mod preview;
+mod schema;
+mod sync;
 
 clawless::commands!();
@@ -0,0 +1,75 @@
+//! Generate the documentation for the labels
+//!
+//! This command renders the labels in the configuration as Markdown, so that contributors can look
+//! up what each label means. The documentation is either written to its own page, or embedded into
+//! an existing document between the markers `<!-- labelflair:start -->` and
+//! `<!-- labelflair:end -->`.
+
+use std::path::{Path, PathBuf};
+
+use clawless::prelude::*;
+use labelflair::docs::{embed, page};
+
+use crate::config::load_config;
+use crate::error::Error;
+
+/// Generate the documentation for the labels
+///
+/// This command writes a Markdown page that lists the labels in the configuration by group, with a
+/// color swatch, the description, and the aliases of each label. With `--embed`, the documentation
+/// replaces the content between the markers `<!-- labelflair:start -->` and
+/// `<!-- labelflair:end -->` in an existing document instead.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct DocsArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+    /// Embed the documentation between the marker comments in an existing document
+    #[clap(long)]
+    embed: bool,
+    /// The path to which the documentation should be written
+    #[clap(default_value = "CONTRIBUTING-labels.md")]
+    path: PathBuf,
+}
+
+/// Generate the documentation for the labels
+///
+/// This function reads the configuration file specified in the arguments and writes the
+/// documentation to the specified path, either as a new page or embedded into the existing file.
+#[command]
+async fn docs(args: DocsArgs, _context: Context) -> CommandResult {
+    write_docs(&args.config, &args.path, args.embed).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Render the documentation and write it to the specified path
+fn write_docs(config_path: &Path, path: &Path, embed_docs: bool) -> Result<(), Error> {
+    let (config, config_content) = load_config(config_path)?;
+
+    let content = if embed_docs {
+        let document = std::fs::read_to_string(path).map_err(|source| Error::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        embed(&document, &config).map_err(|error| match error {
+            labelflair::Error::MissingMarkers { .. } => Error::Embed {
+                path: path.to_path_buf(),
+                source: error,
+            },
+            error => Error::config(config_path, &config_content, error),
+        })?
+    } else {
+        page(&config).map_err(|error| Error::config(config_path, &config_content, error))?
+    };
+
+    std::fs::write(path, content).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    println!("Documentation written to {}", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! List the generated labels in the terminal
+//!
+//! This command generates labels based on the configuration file and prints them to the terminal,
+//! without writing any files. Each label is drawn as a pill in its own color, so that changes to
+//! the configuration can be reviewed while iterating on it.
+
+use std::path::{Path, PathBuf};
+
+use clawless::prelude::*;
+use labelflair::docs::heading;
+use labelflair::label::Label;
+
+use crate::config::load_config;
+use crate::error::Error;
+use crate::terminal::{Painter, Style};
+
+/// List the generated labels in the terminal
+///
+/// This command prints the labels grouped by the groups in the configuration, followed by the
+/// individual labels. Each label is shown with its color, its description, and its aliases. Colors
+/// are disabled when the output is not a terminal or the `NO_COLOR` environment variable is set.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct ListArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+}
+
+/// List the generated labels in the terminal
+///
+/// This function reads the configuration file specified in the arguments, generates the labels,
+/// and prints them to the standard output.
+#[command]
+async fn list(args: ListArgs, _context: Context) -> CommandResult {
+    print_labels(&args.config, &Painter::stdout()).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Generate the labels and print them section by section
+fn print_labels(path: &Path, painter: &Painter) -> Result<(), Error> {
+    let (config, content) = load_config(path)?;
+
+    let mut sections: Vec<(String, Vec<Label>)> = config
+        .groups()
+        .iter()
+        .enumerate()
+        .map(|(index, group)| Ok((heading(index, group), group.expand(config.palettes())?)))
+        .collect::<Result<_, labelflair::Error>>()
+        .map_err(|error| Error::config(path, &content, error))?;
+
+    if !config.labels().is_empty() {
+        sections.push(("Other labels".into(), config.labels().clone()));
+    }
+
+    for (index, (heading, labels)) in sections.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{}", painter.paint(heading, Style::Bold));
+        print_section(labels, painter);
+    }
+
+    Ok(())
+}
+
+/// Print the labels of a section with aligned columns
+fn print_section(labels: &[Label], painter: &Painter) {
+    let width = labels
+        .iter()
+        .map(|label| label.name().get().chars().count())
+        .max()
+        .unwrap_or_default();
+
+    for label in labels {
+        let padding = width - label.name().get().chars().count();
+        let mut line = format!(
+            "  {}{} {}",
+            painter.pill(label.name(), *label.color()),
+            " ".repeat(padding),
+            label.color()
+        );
+
+        if let Some(description) = label.description() {
+            line.push_str("  ");
+            line.push_str(description.get());
+        }
+
+        if !label.aliases().is_empty() {
+            let aliases = label
+                .aliases()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            line.push_str(&painter.paint(format!("  (aliases: {aliases})"), Style::Cyan));
+        }
+
+        println!("{line}");
+    }
+}
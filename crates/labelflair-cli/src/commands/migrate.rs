@@ -0,0 +1,64 @@
+//! Migrate the configuration file to the latest version
+//!
+//! This command rewrites the configuration file in place so that it uses the latest version of the
+//! configuration format. Comments and formatting in the file are preserved where possible.
+
+use std::path::PathBuf;
+
+use clawless::prelude::*;
+use labelflair::config::{Version, migrate as migrate_config};
+
+use crate::config::read_config;
+use crate::error::Error;
+
+/// Migrate the configuration file to the latest version
+///
+/// This command reads the configuration file, applies the changes between its version and the
+/// latest version of the configuration format, and writes the result back to the file.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct MigrateArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+}
+
+/// Migrate the configuration file to the latest version
+///
+/// This function rewrites the configuration file specified in the arguments. Files that already
+/// use the latest version are left untouched.
+#[command]
+async fn migrate(args: MigrateArgs, _context: Context) -> CommandResult {
+    rewrite(&args.config).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Rewrite the configuration file at the specified path with the latest version
+fn rewrite(path: &PathBuf) -> Result<(), Error> {
+    let content = read_config(path)?;
+    let migrated =
+        migrate_config(&content).map_err(|error| Error::config(path, &content, error))?;
+
+    if migrated == content {
+        println!(
+            "{} already uses version {} of the configuration",
+            path.display(),
+            Version::LATEST
+        );
+
+        return Ok(());
+    }
+
+    std::fs::write(path, migrated).map_err(|source| Error::Write {
+        path: path.clone(),
+        source,
+    })?;
+
+    println!(
+        "Migrated {} to version {} of the configuration",
+        path.display(),
+        Version::LATEST
+    );
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Show the changes that would be applied to a repository
+//!
+//! This command generates labels based on the configuration file, compares them with the labels
+//! that currently exist in a repository, and prints the changes that `sync` would apply. The
+//! current labels are either fetched from the REST API of GitHub, GitLab, or Gitea, or read from a
+//! snapshot file.
+
+use std::path::{Path, PathBuf};
+
+use clawless::prelude::*;
+use labelflair::label::Label;
+use labelflair::plan::{Change, Operation, Plan};
+
+use crate::backend::{Features, TargetArgs};
+use crate::config::load_labels;
+use crate::error::Error;
+use crate::terminal::{Painter, Style};
+
+/// The exit code when the plan contains changes
+///
+/// This makes it possible for CI to detect drift between the configuration and the repository. The
+/// code is distinct from the exit code `2` of invalid arguments and from the exit codes of errors,
+/// so that pending changes are never mistaken for a failure.
+const EXIT_CODE_CHANGES: i32 = 8;
+
+/// The fields of a label that a snapshot can contain
+///
+/// Snapshots are parsed as labels, which can have a priority like the labels of a GitLab project.
+/// Whether a label is exclusive is set by its group and is not read from a snapshot.
+const SNAPSHOT_FEATURES: Features = Features {
+    exclusive: false,
+    priority: true,
+};
+
+/// Show the changes that would be applied to a repository
+///
+/// This command compares the labels generated from the configuration file with the labels in a
+/// repository and prints the difference. It exits with code 8 if there are pending changes.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct PlanArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+    /// The repository, project, group, or organization that the labels are compared with
+    #[clap(flatten)]
+    target: TargetArgs,
+    /// A JSON or YAML file with the current labels, instead of fetching them from an API
+    #[clap(short, long, conflicts_with = "target")]
+    labels: Option<PathBuf>,
+    /// Include labels in the repository that are not defined in the configuration as deletions
+    #[clap(long)]
+    delete_other_labels: bool,
+}
+
+/// The number of operations of each kind in a plan
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+struct Summary {
+    /// The number of labels that will be created
+    add: usize,
+    /// The number of labels that will be updated
+    change: usize,
+    /// The number of labels that will be renamed
+    rename: usize,
+    /// The number of labels that will be deleted
+    delete: usize,
+}
+
+/// Show the changes that would be applied to a repository
+///
+/// This function reads the configuration file specified in the arguments, generates the list of
+/// labels, loads the current labels, and prints the planned operations.
+#[command]
+async fn plan(args: PlanArgs, _context: Context) -> CommandResult {
+    let delete_other_labels = args.delete_other_labels;
+    let plan = compute_plan(args).unwrap_or_else(|error| error.exit());
+
+    let summary = print_plan(&plan, delete_other_labels, &Painter::stdout());
+
+    if summary != Summary::default() {
+        std::process::exit(EXIT_CODE_CHANGES);
+    }
+
+    Ok(())
+}
+
+/// Compute the plan between the configuration and the current labels
+fn compute_plan(args: PlanArgs) -> Result<Plan, Error> {
+    let labels = load_labels(&args.config)?;
+
+    let (features, existing) = match &args.labels {
+        Some(path) => (SNAPSHOT_FEATURES, load_snapshot(path)?),
+        None => {
+            let backend = args.target.connect()?;
+            let existing = backend
+                .labels()
+                .map_err(|error| Error::api("list the existing labels", error))?;
+
+            (backend.features(), existing)
+        }
+    };
+
+    let labels: Vec<Label> = labels.iter().map(|label| features.apply(label)).collect();
+
+    Ok(Plan::new(&labels, &existing))
+}
+
+/// Load the current labels from a JSON or YAML file
+///
+/// JSON is a subset of YAML, so both formats are parsed with the YAML parser. The file can be the
+/// output of the GitHub REST API, since unknown fields are ignored. The API returns an empty string
+/// for labels without a description, which is treated like a missing description.
+fn load_snapshot(path: &Path) -> Result<Vec<Label>, Error> {
+    let content = std::fs::read_to_string(path).map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let labels: Vec<Label> = serde_yaml_ng::from_str(&content).map_err(|error| Error::Parse {
+        path: path.to_path_buf(),
+        span: error
+            .location()
+            .map(|location| location.index()..location.index()),
+        message: error.to_string(),
+        content: content.clone(),
+    })?;
+
+    Ok(labels
+        .into_iter()
+        .map(Label::without_empty_description)
+        .collect())
+}
+
+/// Print the operations in the plan and return a summary
+///
+/// Unchanged labels are not printed. Deletions are only printed and counted if labels that are not
+/// defined in the configuration should be deleted.
+fn print_plan(plan: &Plan, delete_other_labels: bool, painter: &Painter) -> Summary {
+    let mut summary = Summary::default();
+
+    for operation in plan.operations() {
+        match operation {
+            Operation::Delete(label) if delete_other_labels => {
+                summary.delete += 1;
+                println!(
+                    "{}",
+                    painter.paint(format!("- {}", label.name()), Style::Red)
+                );
+            }
+            Operation::Rename {
+                from,
+                label,
+                changes,
+            } => {
+                summary.rename += 1;
+                println!(
+                    "{}",
+                    painter.paint(format!("> {from} -> {}", label.name()), Style::Cyan)
+                );
+                print_changes(changes, painter);
+            }
+            Operation::Update { label, changes } => {
+                summary.change += 1;
+                println!(
+                    "{}",
+                    painter.paint(format!("~ {}", label.name()), Style::Yellow)
+                );
+                print_changes(changes, painter);
+            }
+            Operation::Create(label) => {
+                summary.add += 1;
+                println!(
+                    "{}",
+                    painter.paint(format!("+ {}", label.name()), Style::Green)
+                );
+                println!("    color: {}", label.color());
+                if let Some(description) = label.description() {
+                    println!("    description: {description}");
+                }
+                if let Some(priority) = label.priority() {
+                    println!("    priority: {priority}");
+                }
+            }
+            Operation::Delete(_) | Operation::Unchanged(_) => {}
+        }
+    }
+
+    if summary == Summary::default() {
+        println!("No changes. The labels are up-to-date.");
+    } else {
+        println!();
+        println!(
+            "{} {} to add, {} to change, {} to rename, {} to delete.",
+            painter.paint("Plan:", Style::Bold),
+            summary.add,
+            summary.change,
+            summary.rename,
+            summary.delete
+        );
+    }
+
+    summary
+}
+
+/// Print the changes to the fields of a label
+fn print_changes(changes: &[Change], painter: &Painter) {
+    for change in changes {
+        match change {
+            Change::Color { from, to } => {
+                println!(
+                    "    color: {from} {} {to}",
+                    painter.paint("->", Style::Yellow)
+                );
+            }
+            Change::Description { from, to } => {
+                let from = from.as_ref().map(ToString::to_string).unwrap_or_default();
+                let to = to.as_ref().map(ToString::to_string).unwrap_or_default();
+
+                println!(
+                    "    description: {from:?} {} {to:?}",
+                    painter.paint("->", Style::Yellow)
+                );
+            }
+            Change::Exclusive { from, to } => {
+                println!(
+                    "    exclusive: {from} {} {to}",
+                    painter.paint("->", Style::Yellow)
+                );
+            }
+            Change::Priority { from, to } => {
+                let from = from.map(|from| from.to_string()).unwrap_or("none".into());
+                let to = to.map(|to| to.to_string()).unwrap_or("none".into());
+
+                println!(
+                    "    priority: {from} {} {to}",
+                    painter.paint("->", Style::Yellow)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Render a preview of the generated labels
+//!
+//! This command generates labels based on the configuration file and renders them as they would
+//! look on GitHub, in both the light and the dark theme. The preview is written as an HTML page or,
+//! if the output file has the `.svg` extension, as an SVG image.
+
+use std::path::{Path, PathBuf};
+
+use clawless::prelude::*;
+use labelflair::output::Write;
+use labelflair::preview::{Html, Svg};
+
+use crate::config::load_labels;
+use crate::error::Error;
+
+/// Render a preview of the generated labels
+///
+/// This command renders every label as a pill with the colors that GitHub would use, so that the
+/// palette can be reviewed without synchronizing the labels with a repository. The format of the
+/// preview is picked by the extension of the output file: `.svg` for an SVG image, and HTML for
+/// everything else.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct PreviewArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+    /// The path to which the preview should be written, e.g. preview.html or preview.svg
+    #[clap(short, long, default_value = "preview.html")]
+    out: PathBuf,
+}
+
+/// Render a preview of the generated labels
+///
+/// This function reads the configuration file specified in the arguments, generates the list of
+/// labels, and writes the preview to the specified path.
+#[command]
+async fn preview(args: PreviewArgs, _context: Context) -> CommandResult {
+    write_preview(&args.config, &args.out).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Render the preview and write it to the specified path
+fn write_preview(config: &Path, path: &Path) -> Result<(), Error> {
+    let labels = load_labels(config)?;
+
+    let is_svg = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("svg"));
+    let writer: &dyn Write = if is_svg { &Svg } else { &Html };
+
+    let content = writer.write(&labels).map_err(|error| Error::Write {
+        path: path.to_path_buf(),
+        source: std::io::Error::other(error),
+    })?;
+
+    std::fs::write(path, content).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    println!("Preview written to {}", path.display());
+
+    Ok(())
+}
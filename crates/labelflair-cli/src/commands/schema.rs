@@ -0,0 +1,48 @@
+//! Print the JSON Schema for the configuration file
+//!
+//! This command prints a JSON Schema for `labelflair.toml`, which editors can use to provide
+//! completion and validation while editing the configuration.
+
+use std::path::PathBuf;
+
+use clawless::prelude::*;
+use labelflair::config::schema as config_schema;
+
+use crate::error::Error;
+
+/// Print the JSON Schema for the configuration file
+///
+/// This command prints the JSON Schema for the latest version of the configuration. The schema can
+/// be written to a file with the `--output` argument instead.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct SchemaArgs {
+    /// The path to which the schema should be written instead of the standard output
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// Print the JSON Schema for the configuration file
+///
+/// This function generates the schema and prints it to the standard output, or writes it to the
+/// path specified in the arguments.
+#[command]
+async fn schema(args: SchemaArgs, _context: Context) -> CommandResult {
+    write_schema(args.output).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Write the schema to the specified path or the standard output
+fn write_schema(path: Option<PathBuf>) -> Result<(), Error> {
+    let mut json = serde_json::to_string_pretty(&config_schema())
+        .expect("serializing a JSON Schema should never fail");
+    json.push('\n');
+
+    match path {
+        Some(path) => std::fs::write(&path, json).map_err(|source| Error::Write { path, source }),
+        None => {
+            print!("{json}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Synchronize the labels with a repository, project, group, or organization
+//!
+//! This command generates labels based on the configuration file and synchronizes them with a
+//! repository on GitHub, a project or group on GitLab, or a repository or organization on Gitea and
+//! Forgejo, using their REST APIs. Labels that are missing are created, labels that have changed
+//! are updated, and labels that match an alias are renamed. Labels that are not defined in the
+//! configuration can optionally be deleted.
+
+use std::path::PathBuf;
+
+use clawless::prelude::*;
+use labelflair::label::Label;
+use labelflair::plan::{Operation, Plan};
+
+use crate::backend::TargetArgs;
+use crate::config::load_labels;
+use crate::error::Error;
+
+/// Synchronize the labels with a repository, project, group, or organization
+///
+/// This command generates labels based on the configuration file and applies them to GitHub,
+/// GitLab, Gitea, or Forgejo. It authenticates with a token that is read from the `GITHUB_TOKEN`,
+/// `GITLAB_TOKEN`, or `GITEA_TOKEN` environment variable.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Args)]
+struct SyncArgs {
+    /// The path to the configuration file
+    #[clap(short, long, default_value = "labelflair.toml")]
+    config: PathBuf,
+    /// The repository, project, group, or organization that the labels are applied to
+    #[clap(flatten)]
+    target: TargetArgs,
+    /// Delete labels in the repository that are not defined in the configuration
+    #[clap(long)]
+    delete_other_labels: bool,
+}
+
+/// Synchronize the labels with a repository, project, group, or organization
+///
+/// This function reads the configuration file specified in the arguments, generates the list of
+/// labels, fetches the existing labels from the repository, and applies the planned operations.
+#[command]
+async fn sync(args: SyncArgs, _context: Context) -> CommandResult {
+    apply(args).unwrap_or_else(|error| error.exit());
+
+    Ok(())
+}
+
+/// Apply the labels from the configuration to the repository
+fn apply(args: SyncArgs) -> Result<(), Error> {
+    let labels = load_labels(&args.config)?;
+
+    let backend = args.target.connect()?;
+    let features = backend.features();
+    let labels: Vec<Label> = labels.iter().map(|label| features.apply(label)).collect();
+
+    let existing = backend
+        .labels()
+        .map_err(|error| Error::api("list the existing labels", error))?;
+
+    let plan = Plan::new(&labels, &existing);
+
+    for operation in plan.operations() {
+        match operation {
+            Operation::Delete(label) => {
+                if !args.delete_other_labels {
+                    continue;
+                }
+
+                backend
+                    .delete_label(label.name())
+                    .map_err(|error| Error::api(format!("delete label {}", label.name()), error))?;
+                println!("Deleted label {}", label.name());
+            }
+            Operation::Rename { from, label, .. } => {
+                backend
+                    .update_label(from, label)
+                    .map_err(|error| Error::api(format!("rename label {from}"), error))?;
+                println!("Renamed label {from} to {}", label.name());
+            }
+            Operation::Update { label, .. } => {
+                backend
+                    .update_label(label.name(), label)
+                    .map_err(|error| Error::api(format!("update label {}", label.name()), error))?;
+                println!("Updated label {}", label.name());
+            }
+            Operation::Create(label) => {
+                backend
+                    .create_label(label)
+                    .map_err(|error| Error::api(format!("create label {}", label.name()), error))?;
+                println!("Created label {}", label.name());
+            }
+            Operation::Unchanged(_) => {}
+        }
+    }
+
+    Ok(())
+}
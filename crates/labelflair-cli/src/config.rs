@@ -0,0 +1,70 @@
+//! Load the configuration for Labelflair
+//!
+//! Multiple commands need to read the configuration file before they can do their work. This
+//! module provides a shared function to load the configuration from disk.
+
+use std::path::Path;
+
+use labelflair::Labelflair;
+use labelflair::config::Configuration;
+use labelflair::config::v1::ConfigV1;
+use labelflair::diagnostic::Diagnostic;
+use labelflair::label::Label;
+
+use crate::error::Error;
+
+/// Load and validate the configuration from the specified path
+///
+/// This function reads the configuration file at the given path, deserializes it with the version
+/// that the file declares, and converts it into the latest version. The configuration is then
+/// validated, and warnings are printed to the standard error. If the file cannot be read, parsed,
+/// or validated, an error is returned that points to the location of the problem in the file.
+///
+/// The content of the file is returned with the configuration, so that errors that occur later
+/// can be reported in the same way.
+pub fn load_config(path: &Path) -> Result<(ConfigV1, String), Error> {
+    let config_content = read_config(path)?;
+
+    // Deserialize the content into the configuration for its version
+    let configuration: Configuration = config_content
+        .parse()
+        .map_err(|error| Error::config(path, &config_content, error))?;
+    let config = configuration.into_latest();
+
+    let (errors, warnings): (Vec<Diagnostic>, Vec<Diagnostic>) = config
+        .validate()
+        .into_iter()
+        .partition(Diagnostic::is_error);
+
+    for warning in warnings {
+        eprintln!("{warning}");
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::config(
+            path,
+            &config_content,
+            labelflair::Error::Invalid(errors),
+        ));
+    }
+
+    Ok((config, config_content))
+}
+
+/// Read the content of the configuration file at the specified path
+pub fn read_config(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Load the configuration from the specified path and generate the labels
+///
+/// The configuration is validated once before the labels are generated. Warnings are printed to
+/// the standard error, while errors abort the generation and are returned to the caller.
+pub fn load_labels(path: &Path) -> Result<Vec<Label>, Error> {
+    let (config, content) = load_config(path)?;
+
+    Labelflair::expand(&config).map_err(|error| Error::config(path, &content, error))
+}
@@ -0,0 +1,226 @@
+//! Errors in the Labelflair CLI
+//!
+//! This module defines the [`Error`] type for the commands of the CLI. Errors are reported to the
+//! user with as much context as possible, including the location of parse errors in the offending
+//! file, and each kind of error exits the process with its own exit code.
+
+use std::fmt::Write;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use labelflair::diagnostic::Diagnostic;
+use labelflair::label::LabelName;
+use thiserror::Error;
+
+/// The exit code for unexpected errors
+const EXIT_CODE_OTHER: i32 = 1;
+
+/// The exit code when a file could not be read
+const EXIT_CODE_READ: i32 = 3;
+
+/// The exit code when a file could not be parsed
+const EXIT_CODE_PARSE: i32 = 4;
+
+/// The exit code when the configuration is invalid
+const EXIT_CODE_VALIDATION: i32 = 5;
+
+/// The exit code when a file could not be written
+const EXIT_CODE_WRITE: i32 = 6;
+
+/// The exit code when a request to an API failed
+const EXIT_CODE_API: i32 = 7;
+
+/// Errors in the Labelflair CLI
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A file could not be read
+    #[error("failed to read {}: {source}", .path.display())]
+    Read {
+        /// The path of the file
+        path: PathBuf,
+        /// The underlying I/O error
+        source: std::io::Error,
+    },
+
+    /// A file could not be parsed
+    #[error("failed to parse {}: {message}", .path.display())]
+    Parse {
+        /// The path of the file
+        path: PathBuf,
+        /// The content of the file
+        content: String,
+        /// The message of the parser
+        message: String,
+        /// The location of the error in the content
+        span: Option<Range<usize>>,
+    },
+
+    /// The configuration is invalid
+    #[error("{} is invalid", .path.display())]
+    Invalid {
+        /// The path of the configuration
+        path: PathBuf,
+        /// The problems in the configuration
+        diagnostics: Vec<Diagnostic>,
+    },
+
+    /// A file could not be written
+    #[error("failed to write {}: {source}", .path.display())]
+    Write {
+        /// The path of the file
+        path: PathBuf,
+        /// The underlying I/O error
+        source: std::io::Error,
+    },
+
+    /// A request to an API failed
+    #[error("failed to {action}: {source}")]
+    Api {
+        /// A description of the request that failed
+        action: String,
+        /// The underlying error of the backend
+        source: Box<BackendError>,
+    },
+
+    /// The documentation could not be embedded into a document
+    #[error("failed to embed the documentation in {}: {source}", .path.display())]
+    Embed {
+        /// The path of the document
+        path: PathBuf,
+        /// The underlying error
+        source: labelflair::Error,
+    },
+
+    /// A required environment variable is not set
+    #[error("the environment variable {0} must be set")]
+    MissingEnv(&'static str),
+
+    /// No repository, project, or group has been selected
+    #[error(
+        "one of --repo, --gitlab-project, --gitlab-group, --gitea-repo, or --gitea-org must be set"
+    )]
+    MissingTarget,
+}
+
+/// Errors in the backends that host the labels
+#[derive(Debug, Error)]
+pub enum BackendError {
+    /// A request to the API failed
+    #[error(transparent)]
+    Request(#[from] ureq::Error),
+
+    /// The label does not exist on the platform
+    #[error("label {0} does not exist")]
+    LabelNotFound(LabelName),
+}
+
+impl Error {
+    /// Create an error for a failed request to an API
+    pub fn api(action: impl Into<String>, source: BackendError) -> Self {
+        Error::Api {
+            action: action.into(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Create an error for a configuration that could not be loaded
+    pub fn config(path: &Path, content: &str, error: labelflair::Error) -> Self {
+        match error {
+            labelflair::Error::Parse(error) => Error::Parse {
+                path: path.to_path_buf(),
+                content: content.to_string(),
+                message: error.message().to_string(),
+                span: error.span(),
+            },
+            labelflair::Error::Invalid(diagnostics) => Error::Invalid {
+                path: path.to_path_buf(),
+                diagnostics,
+            },
+            error => Error::Invalid {
+                path: path.to_path_buf(),
+                diagnostics: vec![Diagnostic::error(error.to_string())],
+            },
+        }
+    }
+
+    /// Returns the exit code for the error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Read { .. } => EXIT_CODE_READ,
+            Error::Parse { .. } => EXIT_CODE_PARSE,
+            Error::Invalid { .. } => EXIT_CODE_VALIDATION,
+            Error::Write { .. } => EXIT_CODE_WRITE,
+            Error::Api { .. } => EXIT_CODE_API,
+            Error::Embed { .. } | Error::MissingEnv(_) | Error::MissingTarget => EXIT_CODE_OTHER,
+        }
+    }
+
+    /// Render the error for the user
+    ///
+    /// Parse errors are rendered with the path, line, and column of the error, and a snippet of
+    /// the offending line in the file.
+    pub fn report(&self) -> String {
+        match self {
+            Error::Parse {
+                path,
+                content,
+                message,
+                span: Some(span),
+            } => {
+                let (line, column, snippet) = snippet(content, span.clone());
+                let gutter = " ".repeat(line.to_string().len());
+
+                format!(
+                    "error: failed to parse {path}\n{gutter}--> {path}:{line}:{column}\n{snippet}{gutter} = {message}",
+                    path = path.display(),
+                    message = message.trim_end(),
+                )
+            }
+            Error::Invalid { path, diagnostics } => diagnostics
+                .iter()
+                .map(|diagnostic| format!("{diagnostic}\n  --> {}", path.display()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            error => format!("error: {error}"),
+        }
+    }
+
+    /// Report the error on the standard error and exit the process
+    pub fn exit(self) -> ! {
+        eprintln!("{}", self.report());
+        std::process::exit(self.exit_code());
+    }
+}
+
+/// Render a snippet of the line in the content that contains the span
+///
+/// Returns the line and column of the start of the span, both starting at 1, and the snippet with
+/// the span underlined.
+fn snippet(content: &str, span: Range<usize>) -> (usize, usize, String) {
+    let start = span.start.min(content.len());
+    let line_start = content[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = content[start..]
+        .find('\n')
+        .map_or(content.len(), |index| start + index);
+
+    let line = content[..start].matches('\n').count() + 1;
+    let column = content[line_start..start].chars().count() + 1;
+    let text = content[line_start..line_end].trim_end_matches('\r');
+
+    let end = span.end.clamp(start, line_end);
+    let width = content[start..end].chars().count().max(1);
+
+    let gutter = " ".repeat(line.to_string().len());
+    let mut snippet = String::new();
+
+    let _ = writeln!(snippet, "{gutter} |");
+    let _ = writeln!(snippet, "{line} | {text}");
+    let _ = writeln!(
+        snippet,
+        "{gutter} | {}{}",
+        " ".repeat(column - 1),
+        "^".repeat(width)
+    );
+
+    (line, column, snippet)
+}
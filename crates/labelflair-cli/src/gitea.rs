@@ -0,0 +1,512 @@
+//! Client for the Gitea and Forgejo REST API
+//!
+//! This module implements a minimal client for the labels endpoints of the REST API of Gitea, which
+//! Forgejo shares. It can list the existing labels of a repository or organization and create,
+//! update, and delete labels. The base URL of the API is configurable, which makes it possible to
+//! use the client with self-hosted instances, Codeberg, or a local mock server.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use labelflair::color::Color;
+use labelflair::label::{Label, LabelName};
+use percent_encoding::utf8_percent_encode;
+use serde::{Deserialize, Serialize};
+use ureq::Agent;
+
+use crate::backend::{Backend, Features, PATH_SEGMENT};
+use crate::error::BackendError;
+
+/// The default base URL of the Gitea REST API
+pub const DEFAULT_API_URL: &str = "https://gitea.com/api/v1";
+
+/// The environment variable that holds the token for the Gitea REST API
+pub const TOKEN_VARIABLE: &str = "GITEA_TOKEN";
+
+/// The number of labels that are requested per page
+///
+/// Gitea caps the page size at 50 by default, which is lower than the page size of other backends.
+/// Instances can lower the cap even further, so a page with fewer labels is not necessarily the
+/// last page.
+const PER_PAGE: usize = 50;
+
+/// The owner of the labels on Gitea
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Owner {
+    /// A repository in the format `owner/name`
+    Repository(String),
+
+    /// An organization, identified by its name
+    Organization(String),
+}
+
+/// Client for the Gitea and Forgejo REST API
+///
+/// The client is bound to a single repository or organization and authenticates all requests with
+/// a token. Gitea identifies labels by their ID, so the client remembers the IDs of the labels that
+/// it has listed and looks them up by name when a label is updated or deleted.
+#[derive(Clone, Debug)]
+pub struct Gitea {
+    /// The HTTP agent used to send requests
+    agent: Agent,
+
+    /// The base URL of the API without a trailing slash
+    api_url: String,
+
+    /// The repository or organization that owns the labels
+    owner: Owner,
+
+    /// The token used to authenticate with the API
+    token: String,
+
+    /// The IDs of the labels that have been listed, indexed by their name
+    ids: RefCell<HashMap<String, u64>>,
+}
+
+/// A label as returned by the Gitea REST API
+#[derive(Clone, Debug, Deserialize)]
+struct GiteaLabel {
+    /// The ID of the label
+    id: u64,
+
+    /// The name of the label
+    name: String,
+
+    /// The color of the label in hex format
+    color: Color,
+
+    /// The optional description of the label
+    description: Option<String>,
+
+    /// Whether the label is an exclusive scoped label
+    #[serde(default)]
+    exclusive: bool,
+}
+
+/// The request body to create or update a label
+#[derive(Clone, Debug, Serialize)]
+struct EditLabel {
+    /// The name of the label
+    name: String,
+
+    /// The color of the label in hex format with a leading `#`
+    color: String,
+
+    /// The description of the label, with an empty string removing the description
+    description: String,
+
+    /// Whether the label is an exclusive scoped label
+    exclusive: bool,
+}
+
+impl Gitea {
+    /// Create a new client for the given repository or organization
+    pub fn new(api_url: impl Into<String>, owner: Owner, token: impl Into<String>) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent("labelflair")
+            .build()
+            .into();
+
+        Self {
+            agent,
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            owner,
+            token: token.into(),
+            ids: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Return the URL of the labels endpoint of the repository or organization
+    fn labels_url(&self) -> String {
+        match &self.owner {
+            Owner::Repository(repository) => {
+                format!("{}/repos/{repository}/labels", self.api_url)
+            }
+            Owner::Organization(organization) => format!(
+                "{}/orgs/{}/labels",
+                self.api_url,
+                utf8_percent_encode(organization, PATH_SEGMENT)
+            ),
+        }
+    }
+
+    /// Return the URL of the label with the given name
+    ///
+    /// The ID of the label is looked up from the labels that have been listed. If the label is not
+    /// known yet, the labels are listed again, and an error is returned if it still does not exist.
+    fn label_url(&self, name: &LabelName) -> Result<String, BackendError> {
+        if !self.ids.borrow().contains_key(name.get()) {
+            self.labels()?;
+        }
+
+        let id = self
+            .ids
+            .borrow()
+            .get(name.get())
+            .copied()
+            .ok_or_else(|| BackendError::LabelNotFound(name.clone()))?;
+
+        Ok(format!("{}/{id}", self.labels_url()))
+    }
+
+    /// Add the header for authentication to a request
+    fn authenticate<B>(&self, request: ureq::RequestBuilder<B>) -> ureq::RequestBuilder<B> {
+        request.header("Authorization", format!("token {}", self.token))
+    }
+}
+
+impl Backend for Gitea {
+    /// Returns the supported fields, which include exclusive labels but not the priority
+    fn features(&self) -> Features {
+        Features {
+            exclusive: true,
+            priority: false,
+        }
+    }
+
+    /// List all labels in the repository or organization
+    ///
+    /// Gitea paginates the list of labels, so this method requests pages until it receives an empty
+    /// page. Labels of the organization are not included when listing the labels of a
+    /// repository, since they cannot be changed through the repository.
+    fn labels(&self) -> Result<Vec<Label>, BackendError> {
+        let url = self.labels_url();
+        let mut labels = Vec::new();
+
+        for page in 1.. {
+            let mut response = self
+                .authenticate(self.agent.get(&url))
+                .query("limit", PER_PAGE.to_string())
+                .query("page", page.to_string())
+                .call()?;
+
+            let batch: Vec<GiteaLabel> = response.body_mut().read_json()?;
+
+            if batch.is_empty() {
+                break;
+            }
+
+            let mut ids = self.ids.borrow_mut();
+
+            for label in batch {
+                ids.insert(label.name.clone(), label.id);
+                labels.push(Label::from(label));
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Create a new label in the repository or organization
+    fn create_label(&self, label: &Label) -> Result<(), BackendError> {
+        let mut response = self
+            .authenticate(self.agent.post(&self.labels_url()))
+            .send_json(EditLabel::from(label))?;
+
+        let created: GiteaLabel = response.body_mut().read_json()?;
+        self.ids.borrow_mut().insert(created.name, created.id);
+
+        Ok(())
+    }
+
+    /// Update the label with the given name
+    fn update_label(&self, name: &LabelName, label: &Label) -> Result<(), BackendError> {
+        self.authenticate(self.agent.patch(&self.label_url(name)?))
+            .send_json(EditLabel::from(label))?;
+
+        let mut ids = self.ids.borrow_mut();
+
+        if let Some(id) = ids.remove(name.get()) {
+            ids.insert(label.name().to_string(), id);
+        }
+
+        Ok(())
+    }
+
+    /// Delete the label with the given name
+    fn delete_label(&self, name: &LabelName) -> Result<(), BackendError> {
+        self.authenticate(self.agent.delete(&self.label_url(name)?))
+            .call()?;
+
+        self.ids.borrow_mut().remove(name.get());
+
+        Ok(())
+    }
+}
+
+impl From<&Label> for EditLabel {
+    fn from(label: &Label) -> Self {
+        Self {
+            name: label.name().to_string(),
+            color: label.color().to_string(),
+            description: label
+                .description()
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            exclusive: label.exclusive(),
+        }
+    }
+}
+
+impl From<GiteaLabel> for Label {
+    fn from(label: GiteaLabel) -> Self {
+        Label::builder()
+            .name(label.name)
+            .color(label.color)
+            .description(
+                label
+                    .description
+                    .filter(|description| !description.is_empty())
+                    .map(Into::into),
+            )
+            .exclusive(label.exclusive)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use labelflair::plan::{Operation, Plan};
+    use mockito::{Matcher, Mock, Server};
+
+    use super::*;
+
+    /// Mock the empty page that follows the first page of labels
+    fn empty_page(server: &mut Server, path: &str) -> Mock {
+        server
+            .mock("GET", path)
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create()
+    }
+
+    #[test]
+    fn labels() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/repos/jdno/labelflair/labels")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".into(), "50".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .match_header("authorization", "token token")
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": 1, "name": "C/bug", "color": "fca5a5", "description": "A bug", "exclusive": true},
+                    {"id": 2, "name": "C/feature", "color": "b91c1c", "description": ""}
+                ]"#,
+            )
+            .create();
+        let last_page = empty_page(&mut server, "/repos/jdno/labelflair/labels");
+
+        let gitea = Gitea::new(
+            server.url(),
+            Owner::Repository("jdno/labelflair".into()),
+            "token",
+        );
+        let labels = gitea.labels().unwrap();
+
+        mock.assert();
+        last_page.assert();
+        assert_eq!(
+            labels,
+            vec![
+                Label::builder()
+                    .name("C/bug")
+                    .color(Color::from_hex(0xfca5a5))
+                    .description(Some("A bug".into()))
+                    .exclusive(true)
+                    .build(),
+                Label::builder()
+                    .name("C/feature")
+                    .color(Color::from_hex(0xb91c1c))
+                    .build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn labels_with_smaller_pages() {
+        let mut server = Server::new();
+        let mut page = |page: usize, count: usize| {
+            let labels: Vec<String> = (0..count)
+                .map(|index| {
+                    let id = page * 100 + index;
+                    format!(r#"{{"id": {id}, "name": "label-{id}", "color": "fca5a5"}}"#)
+                })
+                .collect();
+
+            server
+                .mock("GET", "/repos/jdno/labelflair/labels")
+                .match_query(Matcher::UrlEncoded("page".into(), page.to_string()))
+                .with_header("content-type", "application/json")
+                .with_body(format!("[{}]", labels.join(",")))
+                .create()
+        };
+        let mocks = [page(1, 30), page(2, 30), page(3, 5), page(4, 0)];
+
+        let gitea = Gitea::new(
+            server.url(),
+            Owner::Repository("jdno/labelflair".into()),
+            "token",
+        );
+        let labels = gitea.labels().unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+        assert_eq!(labels.len(), 65);
+    }
+
+    #[test]
+    fn create_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/orgs/jdno/labels")
+            .match_header("authorization", "token token")
+            .match_body(Matcher::Json(
+                r##"{"name": "C/bug", "color": "#fca5a5", "description": "A bug", "exclusive": true}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id": 7, "name": "C/bug", "color": "fca5a5"}"#)
+            .create();
+
+        let gitea = Gitea::new(server.url(), Owner::Organization("jdno".into()), "token");
+        let label = Label::builder()
+            .name("C/bug")
+            .color(Color::from_hex(0xfca5a5))
+            .description(Some("A bug".into()))
+            .exclusive(true)
+            .build();
+
+        gitea.create_label(&label).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn update_label() {
+        let mut server = Server::new();
+        let list = server
+            .mock("GET", "/repos/jdno/labelflair/labels")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": 42, "name": "good first issue", "color": "4ade80"}]"#)
+            .create();
+        empty_page(&mut server, "/repos/jdno/labelflair/labels");
+        let update = server
+            .mock("PATCH", "/repos/jdno/labelflair/labels/42")
+            .match_body(Matcher::Json(
+                r##"{"name": "good-first-issue", "color": "#4ade80", "description": "", "exclusive": false}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .create();
+
+        let gitea = Gitea::new(
+            server.url(),
+            Owner::Repository("jdno/labelflair".into()),
+            "token",
+        );
+        let label = Label::builder()
+            .name("good-first-issue")
+            .color(Color::from_hex(0x4ade80))
+            .build();
+
+        gitea
+            .update_label(&"good first issue".into(), &label)
+            .unwrap();
+
+        list.assert();
+        update.assert();
+    }
+
+    #[test]
+    fn update_label_exclusive() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/repos/jdno/labelflair/labels")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": 42, "name": "C/bug", "color": "fca5a5", "exclusive": false}]"#)
+            .create();
+        empty_page(&mut server, "/repos/jdno/labelflair/labels");
+        let update = server
+            .mock("PATCH", "/repos/jdno/labelflair/labels/42")
+            .match_body(Matcher::Json(
+                r##"{"name": "C/bug", "color": "#fca5a5", "description": "", "exclusive": true}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .create();
+
+        let gitea = Gitea::new(
+            server.url(),
+            Owner::Repository("jdno/labelflair".into()),
+            "token",
+        );
+        let label = Label::builder()
+            .name("C/bug")
+            .color(Color::from_hex(0xfca5a5))
+            .exclusive(true)
+            .build();
+
+        let existing = gitea.labels().unwrap();
+        let plan = Plan::new(std::slice::from_ref(&label), &existing);
+
+        for operation in plan.operations() {
+            if let Operation::Update { label, .. } = operation {
+                gitea.update_label(label.name(), label).unwrap();
+            }
+        }
+
+        update.assert();
+    }
+
+    #[test]
+    fn delete_label() {
+        let mut server = Server::new();
+        let list = server
+            .mock("GET", "/orgs/jdno/labels")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"id": 3, "name": "C/bug", "color": "fca5a5"}]"#)
+            .expect(1)
+            .create();
+        empty_page(&mut server, "/orgs/jdno/labels");
+        let delete = server
+            .mock("DELETE", "/orgs/jdno/labels/3")
+            .with_status(204)
+            .create();
+
+        let gitea = Gitea::new(server.url(), Owner::Organization("jdno".into()), "token");
+
+        gitea.labels().unwrap();
+        gitea.delete_label(&"C/bug".into()).unwrap();
+
+        list.assert();
+        delete.assert();
+    }
+
+    #[test]
+    fn delete_unknown_label() {
+        let mut server = Server::new();
+        server
+            .mock("GET", "/orgs/jdno/labels")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create();
+
+        let gitea = Gitea::new(server.url(), Owner::Organization("jdno".into()), "token");
+        let error = gitea.delete_label(&"C/bug".into()).unwrap_err();
+
+        assert!(matches!(error, BackendError::LabelNotFound(name) if name.get() == "C/bug"));
+    }
+}
@@ -0,0 +1,331 @@
+//! Client for the GitHub REST API
+//!
+//! This module implements a minimal client for the labels endpoints of the GitHub REST API. It can
+//! list the existing labels of a repository and create, update, and delete labels. The base URL of
+//! the API is configurable, which makes it possible to use the client with GitHub Enterprise Server
+//! or a local mock server.
+
+use labelflair::color::Color;
+use labelflair::label::{Label, LabelName};
+use percent_encoding::utf8_percent_encode;
+use serde::{Deserialize, Serialize};
+use ureq::Agent;
+
+use crate::backend::{Backend, Features, PATH_SEGMENT, PER_PAGE};
+use crate::error::BackendError;
+
+/// The default base URL of the GitHub REST API
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// The environment variable that holds the token for the GitHub REST API
+pub const TOKEN_VARIABLE: &str = "GITHUB_TOKEN";
+
+/// Client for the GitHub REST API
+///
+/// The client is bound to a single repository and authenticates all requests with a token.
+#[derive(Clone, Debug)]
+pub struct GitHub {
+    /// The HTTP agent used to send requests
+    agent: Agent,
+
+    /// The base URL of the API without a trailing slash
+    api_url: String,
+
+    /// The repository in the format `owner/name`
+    repository: String,
+
+    /// The token used to authenticate with the API
+    token: String,
+}
+
+/// A label as returned by the GitHub REST API
+#[derive(Clone, Debug, Deserialize)]
+struct GitHubLabel {
+    /// The name of the label
+    name: String,
+
+    /// The color of the label
+    color: Color,
+
+    /// The optional description of the label
+    description: Option<String>,
+}
+
+/// The request body to create a label
+#[derive(Clone, Debug, Serialize)]
+struct CreateLabel<'a> {
+    /// The name of the label
+    name: String,
+
+    /// The color of the label in hex format without a leading `#`
+    color: &'a str,
+
+    /// The description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+/// The request body to update a label
+#[derive(Clone, Debug, Serialize)]
+struct UpdateLabel<'a> {
+    /// The new name of the label
+    new_name: String,
+
+    /// The color of the label in hex format without a leading `#`
+    color: &'a str,
+
+    /// The description of the label, with an empty string removing the description
+    description: String,
+}
+
+impl GitHub {
+    /// Create a new client for the given repository
+    pub fn new(
+        api_url: impl Into<String>,
+        repository: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent("labelflair")
+            .build()
+            .into();
+
+        Self {
+            agent,
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            repository: repository.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Return the URL of the label with the given name
+    fn label_url(&self, name: &LabelName) -> String {
+        let name = name.to_string();
+        let encoded_name = utf8_percent_encode(&name, PATH_SEGMENT);
+
+        format!(
+            "{}/repos/{}/labels/{encoded_name}",
+            self.api_url, self.repository
+        )
+    }
+
+    /// Add the headers for authentication and versioning to a request
+    fn authenticate<B>(&self, request: ureq::RequestBuilder<B>) -> ureq::RequestBuilder<B> {
+        request
+            .header("Accept", "application/vnd.github+json")
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("X-GitHub-Api-Version", "2022-11-28")
+    }
+}
+
+impl Backend for GitHub {
+    /// Returns the supported fields, which include neither exclusive labels nor the priority
+    fn features(&self) -> Features {
+        Features::default()
+    }
+
+    /// List all labels in the repository
+    ///
+    /// GitHub paginates the list of labels, so this method requests pages until it receives a page
+    /// that is not full.
+    fn labels(&self) -> Result<Vec<Label>, BackendError> {
+        let url = format!("{}/repos/{}/labels", self.api_url, self.repository);
+        let mut labels = Vec::new();
+
+        for page in 1.. {
+            let mut response = self
+                .authenticate(self.agent.get(&url))
+                .query("per_page", PER_PAGE.to_string())
+                .query("page", page.to_string())
+                .call()?;
+
+            let batch: Vec<GitHubLabel> = response.body_mut().read_json()?;
+            let is_last_page = batch.len() < PER_PAGE;
+
+            labels.extend(batch.into_iter().map(Label::from));
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Create a new label in the repository
+    fn create_label(&self, label: &Label) -> Result<(), BackendError> {
+        let url = format!("{}/repos/{}/labels", self.api_url, self.repository);
+        let color = label.color().to_string();
+
+        let body = CreateLabel {
+            name: label.name().to_string(),
+            color: color.trim_start_matches('#'),
+            description: label.description().as_ref().map(ToString::to_string),
+        };
+
+        self.authenticate(self.agent.post(&url)).send_json(&body)?;
+
+        Ok(())
+    }
+
+    /// Update the label with the given name
+    ///
+    /// The label is identified by its current name, which makes it possible to rename the label by
+    /// passing a label with a different name.
+    fn update_label(&self, name: &LabelName, label: &Label) -> Result<(), BackendError> {
+        let url = self.label_url(name);
+        let color = label.color().to_string();
+
+        let body = UpdateLabel {
+            new_name: label.name().to_string(),
+            color: color.trim_start_matches('#'),
+            description: label
+                .description()
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        };
+
+        self.authenticate(self.agent.patch(&url)).send_json(&body)?;
+
+        Ok(())
+    }
+
+    /// Delete the label with the given name
+    fn delete_label(&self, name: &LabelName) -> Result<(), BackendError> {
+        let url = self.label_url(name);
+
+        self.authenticate(self.agent.delete(&url)).call()?;
+
+        Ok(())
+    }
+}
+
+impl From<GitHubLabel> for Label {
+    fn from(label: GitHubLabel) -> Self {
+        Label::builder()
+            .name(label.name)
+            .color(label.color)
+            .description(
+                label
+                    .description
+                    .filter(|description| !description.is_empty())
+                    .map(Into::into),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Server};
+
+    use super::*;
+
+    #[test]
+    fn labels() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/repos/jdno/labelflair/labels")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("per_page".into(), "100".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .match_header("authorization", "Bearer token")
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"id": 1, "name": "C-bug", "color": "fca5a5", "description": "A bug"},
+                    {"id": 2, "name": "C-feature", "color": "b91c1c", "description": null}
+                ]"#,
+            )
+            .create();
+
+        let github = GitHub::new(server.url(), "jdno/labelflair", "token");
+        let labels = github.labels().unwrap();
+
+        mock.assert();
+        assert_eq!(
+            labels,
+            vec![
+                Label::builder()
+                    .name("C-bug")
+                    .color(Color::from_hex(0xfca5a5))
+                    .description(Some("A bug".into()))
+                    .build(),
+                Label::builder()
+                    .name("C-feature")
+                    .color(Color::from_hex(0xb91c1c))
+                    .build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn create_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/repos/jdno/labelflair/labels")
+            .match_body(Matcher::Json(
+                r#"{"name": "C-bug", "color": "fca5a5", "description": "A bug"}"#
+                    .parse()
+                    .unwrap(),
+            ))
+            .with_status(201)
+            .create();
+
+        let github = GitHub::new(server.url(), "jdno/labelflair", "token");
+        let label = Label::builder()
+            .name("C-bug")
+            .color(Color::from_hex(0xfca5a5))
+            .description(Some("A bug".into()))
+            .build();
+
+        github.create_label(&label).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn update_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock(
+                "PATCH",
+                "/repos/jdno/labelflair/labels/good%20first%20issue",
+            )
+            .match_body(Matcher::Json(
+                r#"{"new_name": "good-first-issue", "color": "4ade80", "description": ""}"#
+                    .parse()
+                    .unwrap(),
+            ))
+            .create();
+
+        let github = GitHub::new(server.url(), "jdno/labelflair", "token");
+        let label = Label::builder()
+            .name("good-first-issue")
+            .color(Color::from_hex(0x4ade80))
+            .build();
+
+        github
+            .update_label(&"good first issue".into(), &label)
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("DELETE", "/repos/jdno/labelflair/labels/C-bug")
+            .with_status(204)
+            .create();
+
+        let github = GitHub::new(server.url(), "jdno/labelflair", "token");
+
+        github.delete_label(&"C-bug".into()).unwrap();
+
+        mock.assert();
+    }
+}
@@ -0,0 +1,398 @@
+//! Client for the GitLab REST API
+//!
+//! This module implements a minimal client for the labels endpoints of the GitLab REST API. It can
+//! list the existing labels of a project or group and create, update, and delete labels. The base
+//! URL of the API is configurable, which makes it possible to use the client with self-hosted
+//! instances of GitLab or a local mock server.
+
+use labelflair::color::Color;
+use labelflair::label::{Label, LabelName};
+use percent_encoding::utf8_percent_encode;
+use serde::{Deserialize, Serialize};
+use ureq::Agent;
+
+use crate::backend::{Backend, Features, PATH_SEGMENT, PER_PAGE};
+use crate::error::BackendError;
+
+/// The default base URL of the GitLab REST API
+pub const DEFAULT_API_URL: &str = "https://gitlab.com/api/v4";
+
+/// The environment variable that holds the token for the GitLab REST API
+pub const TOKEN_VARIABLE: &str = "GITLAB_TOKEN";
+
+/// The namespace that owns the labels on GitLab
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Namespace {
+    /// A project, identified by its ID or path
+    Project(String),
+
+    /// A group, identified by its ID or path
+    Group(String),
+}
+
+/// Client for the GitLab REST API
+///
+/// The client is bound to a single project or group and authenticates all requests with a token.
+#[derive(Clone, Debug)]
+pub struct GitLab {
+    /// The HTTP agent used to send requests
+    agent: Agent,
+
+    /// The base URL of the API without a trailing slash
+    api_url: String,
+
+    /// The project or group that owns the labels
+    namespace: Namespace,
+
+    /// The token used to authenticate with the API
+    token: String,
+}
+
+/// A label as returned by the GitLab REST API
+#[derive(Clone, Debug, Deserialize)]
+struct GitLabLabel {
+    /// The name of the label
+    name: String,
+
+    /// The color of the label in hex format with a leading `#`
+    color: Color,
+
+    /// The optional description of the label
+    description: Option<String>,
+
+    /// The optional priority of the label, which only project labels have
+    #[serde(default)]
+    priority: Option<u32>,
+}
+
+/// The request body to create a label
+#[derive(Clone, Debug, Serialize)]
+struct CreateLabel {
+    /// The name of the label
+    name: String,
+
+    /// The color of the label in hex format with a leading `#`
+    color: String,
+
+    /// The description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// The priority of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u32>,
+}
+
+/// The request body to update a label
+#[derive(Clone, Debug, Serialize)]
+struct UpdateLabel {
+    /// The new name of the label
+    new_name: String,
+
+    /// The color of the label in hex format with a leading `#`
+    color: String,
+
+    /// The description of the label, with an empty string removing the description
+    description: String,
+
+    /// The priority of the label, with `null` removing the priority
+    ///
+    /// Only the labels of a project have a priority, so the field is omitted for groups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<Option<u32>>,
+}
+
+impl GitLab {
+    /// Create a new client for the given project or group
+    pub fn new(api_url: impl Into<String>, namespace: Namespace, token: impl Into<String>) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent("labelflair")
+            .build()
+            .into();
+
+        Self {
+            agent,
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            namespace,
+            token: token.into(),
+        }
+    }
+
+    /// Return the URL of the labels endpoint of the project or group
+    ///
+    /// Paths of projects and groups contain slashes, which must be encoded in the URL.
+    fn labels_url(&self) -> String {
+        let (kind, id) = match &self.namespace {
+            Namespace::Project(id) => ("projects", id),
+            Namespace::Group(id) => ("groups", id),
+        };
+
+        format!(
+            "{}/{kind}/{}/labels",
+            self.api_url,
+            utf8_percent_encode(id, PATH_SEGMENT)
+        )
+    }
+
+    /// Return the URL of the label with the given name
+    ///
+    /// The GitLab API accepts the name of a label instead of its ID.
+    fn label_url(&self, name: &LabelName) -> String {
+        let name = name.to_string();
+
+        format!(
+            "{}/{}",
+            self.labels_url(),
+            utf8_percent_encode(&name, PATH_SEGMENT)
+        )
+    }
+
+    /// Add the header for authentication to a request
+    fn authenticate<B>(&self, request: ureq::RequestBuilder<B>) -> ureq::RequestBuilder<B> {
+        request.header("PRIVATE-TOKEN", &self.token)
+    }
+}
+
+impl Backend for GitLab {
+    /// Returns the supported fields, which include the priority only for the labels of a project
+    fn features(&self) -> Features {
+        Features {
+            exclusive: false,
+            priority: matches!(self.namespace, Namespace::Project(_)),
+        }
+    }
+
+    /// List all labels in the project or group
+    ///
+    /// GitLab paginates the list of labels, so this method requests pages until it receives a page
+    /// that is not full. Labels that are inherited from parent groups are not included, since they
+    /// cannot be changed through the project or group.
+    fn labels(&self) -> Result<Vec<Label>, BackendError> {
+        let url = self.labels_url();
+        let mut labels = Vec::new();
+
+        for page in 1.. {
+            let mut response = self
+                .authenticate(self.agent.get(&url))
+                .query("include_ancestor_groups", "false")
+                .query("per_page", PER_PAGE.to_string())
+                .query("page", page.to_string())
+                .call()?;
+
+            let batch: Vec<GitLabLabel> = response.body_mut().read_json()?;
+            let is_last_page = batch.len() < PER_PAGE;
+
+            labels.extend(batch.into_iter().map(Label::from));
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(labels)
+    }
+
+    /// Create a new label in the project or group
+    fn create_label(&self, label: &Label) -> Result<(), BackendError> {
+        let body = CreateLabel {
+            name: label.name().to_string(),
+            color: label.color().to_string(),
+            description: label.description().as_ref().map(ToString::to_string),
+            priority: label.priority(),
+        };
+
+        self.authenticate(self.agent.post(&self.labels_url()))
+            .send_json(&body)?;
+
+        Ok(())
+    }
+
+    /// Update the label with the given name
+    fn update_label(&self, name: &LabelName, label: &Label) -> Result<(), BackendError> {
+        let body = UpdateLabel {
+            new_name: label.name().to_string(),
+            color: label.color().to_string(),
+            description: label
+                .description()
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            priority: self.features().priority.then_some(label.priority()),
+        };
+
+        self.authenticate(self.agent.put(&self.label_url(name)))
+            .send_json(&body)?;
+
+        Ok(())
+    }
+
+    /// Delete the label with the given name
+    fn delete_label(&self, name: &LabelName) -> Result<(), BackendError> {
+        self.authenticate(self.agent.delete(&self.label_url(name)))
+            .call()?;
+
+        Ok(())
+    }
+}
+
+impl From<GitLabLabel> for Label {
+    fn from(label: GitLabLabel) -> Self {
+        Label::builder()
+            .name(label.name)
+            .color(label.color)
+            .description(
+                label
+                    .description
+                    .filter(|description| !description.is_empty())
+                    .map(Into::into),
+            )
+            .priority(label.priority)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{Matcher, Server};
+
+    use super::*;
+
+    #[test]
+    fn labels() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/projects/jdno%2Flabelflair/labels")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("include_ancestor_groups".into(), "false".into()),
+                Matcher::UrlEncoded("per_page".into(), "100".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .match_header("private-token", "token")
+            .with_header("content-type", "application/json")
+            .with_body(
+                r##"[
+                    {"id": 1, "name": "C-bug", "color": "#fca5a5", "description": "A bug", "priority": null},
+                    {"id": 2, "name": "C-feature", "color": "#b91c1c", "description": null, "priority": 1}
+                ]"##,
+            )
+            .create();
+
+        let gitlab = GitLab::new(
+            server.url(),
+            Namespace::Project("jdno/labelflair".into()),
+            "token",
+        );
+        let labels = gitlab.labels().unwrap();
+
+        mock.assert();
+        assert_eq!(
+            labels,
+            vec![
+                Label::builder()
+                    .name("C-bug")
+                    .color(Color::from_hex(0xfca5a5))
+                    .description(Some("A bug".into()))
+                    .build(),
+                Label::builder()
+                    .name("C-feature")
+                    .color(Color::from_hex(0xb91c1c))
+                    .priority(Some(1))
+                    .build(),
+            ]
+        );
+    }
+
+    #[test]
+    fn create_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/groups/jdno/labels")
+            .match_header("private-token", "token")
+            .match_body(Matcher::Json(
+                r##"{"name": "C-bug", "color": "#fca5a5", "description": "A bug"}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .with_status(201)
+            .create();
+
+        let gitlab = GitLab::new(server.url(), Namespace::Group("jdno".into()), "token");
+        let label = Label::builder()
+            .name("C-bug")
+            .color(Color::from_hex(0xfca5a5))
+            .description(Some("A bug".into()))
+            .build();
+
+        gitlab.create_label(&label).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn update_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("PUT", "/projects/42/labels/good%20first%20issue")
+            .match_body(Matcher::Json(
+                r##"{"new_name": "good-first-issue", "color": "#4ade80", "description": "", "priority": 2}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .create();
+
+        let gitlab = GitLab::new(server.url(), Namespace::Project("42".into()), "token");
+        let label = Label::builder()
+            .name("good-first-issue")
+            .color(Color::from_hex(0x4ade80))
+            .priority(Some(2))
+            .build();
+
+        gitlab
+            .update_label(&"good first issue".into(), &label)
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn update_group_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("PUT", "/groups/jdno/labels/C-bug")
+            .match_body(Matcher::Json(
+                r##"{"new_name": "C-bug", "color": "#fca5a5", "description": ""}"##
+                    .parse()
+                    .unwrap(),
+            ))
+            .create();
+
+        let gitlab = GitLab::new(server.url(), Namespace::Group("jdno".into()), "token");
+        let label = Label::builder()
+            .name("C-bug")
+            .color(Color::from_hex(0xfca5a5))
+            .build();
+
+        gitlab.update_label(&"C-bug".into(), &label).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_label() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("DELETE", "/groups/jdno%2Fplatform/labels/C-bug")
+            .with_status(204)
+            .create();
+
+        let gitlab = GitLab::new(
+            server.url(),
+            Namespace::Group("jdno/platform".into()),
+            "token",
+        );
+
+        gitlab.delete_label(&"C-bug".into()).unwrap();
+
+        mock.assert();
+    }
+}
@@ -8,6 +8,13 @@
 #![warn(missing_docs)]
 #![warn(clippy::missing_docs_in_private_items)]
 
+mod backend;
 mod commands;
+mod config;
+mod error;
+mod gitea;
+mod github;
+mod gitlab;
+mod terminal;
 
 clawless::main!();
@@ -0,0 +1,215 @@
+//! Styled output for the terminal
+//!
+//! This module provides a small helper to color text that is printed to the terminal. Colors are
+//! only used when the output is a terminal and the `NO_COLOR` environment variable is not set, so
+//! that output that is piped into a file or another program stays free of escape sequences.
+//!
+//! Labels are drawn with their own colors. Terminals that announce support for 24-bit colors in the
+//! `COLORTERM` environment variable get the exact colors, while all other terminals get the closest
+//! colors from the 256-color palette.
+
+use std::fmt::Display;
+use std::io::IsTerminal;
+
+use labelflair::color::Color;
+
+/// A style that can be applied to text
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Style {
+    /// Bold text
+    Bold,
+
+    /// Cyan text
+    Cyan,
+
+    /// Green text
+    Green,
+
+    /// Red text
+    Red,
+
+    /// Yellow text
+    Yellow,
+}
+
+/// The colors that a terminal can display
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Depth {
+    /// The 256 colors of the extended ANSI palette
+    Ansi256,
+
+    /// Any 24-bit color
+    TrueColor,
+}
+
+/// Apply styles to text that is printed to the terminal
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Painter {
+    /// Whether styles are applied to the text
+    enabled: bool,
+
+    /// The colors that the terminal can display
+    depth: Depth,
+}
+
+/// The levels of each component in the color cube of the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Style {
+    /// Returns the ANSI escape code for the style
+    fn code(&self) -> &'static str {
+        match self {
+            Style::Bold => "1",
+            Style::Cyan => "36",
+            Style::Green => "32",
+            Style::Red => "31",
+            Style::Yellow => "33",
+        }
+    }
+}
+
+impl Painter {
+    /// Create a painter for the standard output
+    ///
+    /// Styles are enabled if the standard output is a terminal and `NO_COLOR` is not set.
+    pub fn stdout() -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let true_color =
+            std::env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit");
+
+        Self {
+            enabled: !no_color && std::io::stdout().is_terminal(),
+            depth: if true_color {
+                Depth::TrueColor
+            } else {
+                Depth::Ansi256
+            },
+        }
+    }
+
+    /// Apply the style to the text
+    pub fn paint(&self, text: impl Display, style: Style) -> String {
+        if self.enabled {
+            format!("\x1b[{}m{text}\x1b[0m", style.code())
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Draw the text as a pill with the given background color
+    ///
+    /// The text is black or white, whichever has the higher contrast with the background. Without
+    /// styles, the text is returned with the same padding, so that columns stay aligned.
+    pub fn pill(&self, text: impl Display, background: Color) -> String {
+        if !self.enabled {
+            return format!(" {text} ");
+        }
+
+        let foreground = background.contrast().readable_text_color();
+
+        format!(
+            "\x1b[48;{};38;{}m {text} \x1b[0m",
+            self.color(background),
+            self.color(foreground)
+        )
+    }
+
+    /// Returns the parameters of the escape code for a color in the depth of the terminal
+    fn color(&self, color: Color) -> String {
+        match self.depth {
+            Depth::TrueColor => {
+                let (red, green, blue) = color.to_rgb();
+
+                format!("2;{red};{green};{blue}")
+            }
+            Depth::Ansi256 => format!("5;{}", ansi256(color)),
+        }
+    }
+}
+
+/// Returns the index of the closest color in the 256-color palette
+///
+/// The palette has a 6x6x6 color cube and a ramp of 24 grays. The closest color in the cube and
+/// the closest gray are compared, and the one that is closer to the color wins.
+fn ansi256(color: Color) -> u8 {
+    let (red, green, blue) = color.to_rgb();
+
+    let level = |component: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&index| CUBE_LEVELS[index].abs_diff(component))
+            .unwrap_or_default()
+    };
+    let (r, g, b) = (level(red), level(green), level(blue));
+    let cube = Color::new(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+
+    let average = (u16::from(red) + u16::from(green) + u16::from(blue)) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let value = 8 + step * 10;
+    let gray = Color::new(value, value, value);
+
+    if distance(color, gray) < distance(color, cube) {
+        232 + step
+    } else {
+        16 + 36 * r as u8 + 6 * g as u8 + b as u8
+    }
+}
+
+/// Returns the squared distance between two colors
+fn distance(a: Color, b: Color) -> u32 {
+    let (ar, ag, ab) = a.to_rgb();
+    let (br, bg, bb) = b.to_rgb();
+
+    [(ar, br), (ag, bg), (ab, bb)]
+        .into_iter()
+        .map(|(x, y)| u32::from(x.abs_diff(y)).pow(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ansi256_picks_closest_color() {
+        assert_eq!(ansi256(Color::new(255, 0, 0)), 196);
+        assert_eq!(ansi256(Color::new(0, 0, 0)), 16);
+        assert_eq!(ansi256(Color::new(128, 128, 128)), 244);
+        assert_eq!(ansi256(Color::from_hex(0x4ade80)), 78);
+    }
+
+    #[test]
+    fn pill_without_styles() {
+        let painter = Painter {
+            enabled: false,
+            depth: Depth::TrueColor,
+        };
+
+        assert_eq!(painter.pill("C-bug", Color::from_hex(0xfca5a5)), " C-bug ");
+    }
+
+    #[test]
+    fn pill_with_true_color() {
+        let painter = Painter {
+            enabled: true,
+            depth: Depth::TrueColor,
+        };
+
+        assert_eq!(
+            painter.pill("C-bug", Color::from_hex(0xb91c1c)),
+            "\x1b[48;2;185;28;28;38;2;255;255;255m C-bug \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn pill_with_256_colors() {
+        let painter = Painter {
+            enabled: true,
+            depth: Depth::Ansi256,
+        };
+
+        assert_eq!(
+            painter.pill("C-bug", Color::from_hex(0xfca5a5)),
+            "\x1b[48;5;217;38;5;16m C-bug \x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,27 @@
+variable "repository" {
+  description = "The name of the repository for the labels"
+  type        = string
+}
+
+resource "github_issue_label" "c_bug" {
+  repository  = var.repository
+  name        = "C-bug"
+  color       = "fecaca"
+}
+
+resource "github_issue_label" "c_feature" {
+  repository  = var.repository
+  name        = "C-feature"
+  color       = "ef4444"
+}
+
+resource "github_issue_label" "c_documentation" {
+  repository  = var.repository
+  name        = "C-documentation"
+  color       = "991b1b"
+}
+
+moved {
+  from = github_issue_label.docs
+  to   = github_issue_label.c_documentation
+}
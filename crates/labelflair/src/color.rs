@@ -0,0 +1,510 @@
+//! Colors and conversions between color spaces
+//!
+//! This module defines the [`Color`] type, which is used for the colors of labels. Colors can be
+//! parsed from hex codes, the CSS functions `rgb()` and `hsl()`, and CSS color names, and are always
+//! printed in the canonical form `#rrggbb` that GitHub expects. They can be converted into the
+//! [`Hct`], [`Hsl`], and [`Oklch`] color spaces, which makes it possible to compute new colors, and
+//! their [`Contrast`] with black and white text can be measured.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use getset::CopyGetters;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Error;
+
+pub use self::contrast::Contrast;
+pub use self::hct::Hct;
+pub use self::hsl::Hsl;
+pub use self::oklch::Oklch;
+
+mod contrast;
+mod hct;
+mod hsl;
+mod named;
+mod oklch;
+
+/// A color in the sRGB color space
+///
+/// A `Color` is stored as its red, green, and blue components. It can be parsed from a string in one
+/// of the following formats:
+///
+/// - Hex codes with three or six digits, with or without a leading `#`, e.g. `#4ade80` or `f00`
+/// - The CSS function `rgb()`, e.g. `rgb(74, 222, 128)` or `rgb(29% 87% 50%)`
+/// - The CSS function `hsl()`, e.g. `hsl(142, 69%, 58%)`
+/// - CSS color names, e.g. `rebeccapurple`
+///
+/// Regardless of the input, colors are always formatted and serialized as `#rrggbb`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, CopyGetters)]
+pub struct Color {
+    /// The red component of the color
+    #[getset(get_copy = "pub")]
+    red: u8,
+
+    /// The green component of the color
+    #[getset(get_copy = "pub")]
+    green: u8,
+
+    /// The blue component of the color
+    #[getset(get_copy = "pub")]
+    blue: u8,
+}
+
+impl Color {
+    /// The color black
+    pub const BLACK: Color = Color::new(0, 0, 0);
+
+    /// The color white
+    pub const WHITE: Color = Color::new(255, 255, 255);
+
+    /// Create a new color from its red, green, and blue components
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Create a new color from a hex number like `0x4ade80`
+    ///
+    /// Only the lower 24 bits of the number are used.
+    pub const fn from_hex(hex: u32) -> Self {
+        Self::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    /// Returns the red, green, and blue components of the color
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Converts the color into the HCT color space of Material Design
+    pub fn to_hct(&self) -> Hct {
+        Hct::from(*self)
+    }
+
+    /// Converts the color into the HSL color space
+    pub fn to_hsl(&self) -> Hsl {
+        Hsl::from(*self)
+    }
+
+    /// Converts the color into the OKLCH color space
+    pub fn to_oklch(&self) -> Oklch {
+        Oklch::from(*self)
+    }
+
+    /// Returns the relative luminance of the color
+    ///
+    /// The relative luminance is defined by the [Web Content Accessibility Guidelines][wcag] and
+    /// ranges from `0.0` for black to `1.0` for white.
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(&self) -> f64 {
+        let [red, green, blue] = self.to_linear();
+
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+
+    /// Returns the contrast ratio between this and another color
+    ///
+    /// The contrast ratio is defined by the [Web Content Accessibility Guidelines][wcag] and ranges
+    /// from `1.0` for two identical colors to `21.0` for black and white. The order of the colors
+    /// does not matter.
+    ///
+    /// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(&self, other: Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the contrast of the color with black and white text
+    pub fn contrast(&self) -> Contrast {
+        Contrast::new(*self)
+    }
+
+    /// Returns the perceived lightness of the color between `0.0` and `1.0`
+    ///
+    /// The perceived lightness is a weighted sum of the color components without converting them
+    /// into linear light. GitHub uses it to pick the text color of a label.
+    pub fn perceived_lightness(&self) -> f64 {
+        (f64::from(self.red) * 0.2126
+            + f64::from(self.green) * 0.7152
+            + f64::from(self.blue) * 0.0722)
+            / 255.0
+    }
+
+    /// Returns the color of the text that GitHub draws on a label with this color
+    ///
+    /// GitHub picks white text for labels with a perceived lightness below `0.453`, and black text
+    /// otherwise. This is not always the text color with the higher contrast, which is returned by
+    /// [`Contrast::readable_text_color`].
+    pub fn text_color(&self) -> Color {
+        if self.perceived_lightness() < 0.453 {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        }
+    }
+
+    /// Returns the components of the color in linear light, each in the range `0.0..=1.0`
+    fn to_linear(self) -> [f64; 3] {
+        [self.red, self.green, self.blue].map(|component| {
+            let value = f64::from(component) / 255.0;
+
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    }
+
+    /// Create a color from components in linear light, clamping them to the range `0.0..=1.0`
+    fn from_linear(components: [f64; 3]) -> Self {
+        let [red, green, blue] = components.map(|component| {
+            let value = component.clamp(0.0, 1.0);
+
+            let value = if value <= 0.0031308 {
+                value * 12.92
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            };
+
+            to_byte(value)
+        });
+
+        Self::new(red, green, blue)
+    }
+}
+
+/// Convert a component in the range `0.0..=1.0` to a byte
+fn to_byte(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Parse a hex code with three or six digits and an optional `#`
+fn parse_hex(input: &str) -> Option<Color> {
+    let digits = input.strip_prefix('#').unwrap_or(input);
+
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let hex = u32::from_str_radix(digits, 16).ok()?;
+
+    match digits.len() {
+        3 => {
+            let expand = |digit: u32| ((digit & 0xf) * 0x11) as u8;
+            Some(Color::new(expand(hex >> 8), expand(hex >> 4), expand(hex)))
+        }
+        6 => Some(Color::from_hex(hex)),
+        _ => None,
+    }
+}
+
+/// Returns the arguments of a CSS function call like `rgb(1, 2, 3)`
+///
+/// The arguments can be separated by commas or whitespace.
+fn arguments<'a>(input: &'a str, function: &str) -> Option<Vec<&'a str>> {
+    let arguments = input
+        .strip_prefix(function)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+
+    Some(
+        arguments
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|argument| !argument.is_empty())
+            .collect(),
+    )
+}
+
+/// Parse the arguments of the CSS function `rgb()`
+fn parse_rgb(arguments: &[&str]) -> Result<Color, String> {
+    let [red, green, blue] = arguments else {
+        return Err("must have three components like `rgb(74, 222, 128)`".into());
+    };
+
+    let component = |argument: &str| {
+        let value = match argument.strip_suffix('%') {
+            Some(percentage) => percentage.parse::<f64>().ok().map(|value| value / 100.0),
+            None => argument.parse::<f64>().ok().map(|value| value / 255.0),
+        };
+
+        value
+            .filter(|value| (0.0..=1.0).contains(value))
+            .map(to_byte)
+            .ok_or_else(|| {
+                format!(
+                    "has the component `{argument}`, which is not between 0 and 255 or 0% and 100%"
+                )
+            })
+    };
+
+    Ok(Color::new(
+        component(red)?,
+        component(green)?,
+        component(blue)?,
+    ))
+}
+
+/// Parse the arguments of the CSS function `hsl()`
+fn parse_hsl(arguments: &[&str]) -> Result<Color, String> {
+    let [hue, saturation, lightness] = arguments else {
+        return Err("must have three components like `hsl(142, 69%, 58%)`".into());
+    };
+
+    let hue = hue
+        .strip_suffix("deg")
+        .unwrap_or(hue)
+        .parse::<f64>()
+        .ok()
+        .filter(|hue| hue.is_finite())
+        .ok_or_else(|| format!("has the hue `{hue}`, which is not a number of degrees"))?;
+
+    let percentage = |argument: &str| {
+        argument
+            .strip_suffix('%')
+            .and_then(|percentage| percentage.parse::<f64>().ok())
+            .filter(|value| (0.0..=100.0).contains(value))
+            .map(|value| value / 100.0)
+            .ok_or_else(|| {
+                format!(
+                    "has the component `{argument}`, which is not a percentage between 0% and 100%"
+                )
+            })
+    };
+
+    Ok(Hsl::new(hue, percentage(saturation)?, percentage(lightness)?).into())
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_lowercase();
+
+        let result = if let Some(arguments) = arguments(&normalized, "rgb") {
+            parse_rgb(&arguments)
+        } else if let Some(arguments) = arguments(&normalized, "hsl") {
+            parse_hsl(&arguments)
+        } else if let Some(color) = parse_hex(&normalized) {
+            Ok(color)
+        } else if let Some(hex) = named::lookup(&normalized) {
+            Ok(Color::from_hex(hex))
+        } else {
+            Err("is not a hex code like `#4ade80`, an `rgb()` or `hsl()` color, or a CSS color name".into())
+        };
+
+        result.map_err(|reason| Error::InvalidColor {
+            color: input.to_string(),
+            reason,
+        })
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_color()
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        oklch.to_color()
+    }
+}
+
+impl JsonSchema for Color {
+    fn schema_name() -> Cow<'static, str> {
+        "Color".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "A color as a hex code with three or six digits, an `rgb()` or `hsl()` color, or a CSS color name",
+            "examples": ["#4ade80", "rgb(74, 222, 128)", "hsl(142, 69%, 58%)", "rebeccapurple"],
+        })
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Color {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn from_hex() {
+        assert_eq!(Color::from_hex(0x4ade80), Color::new(0x4a, 0xde, 0x80));
+    }
+
+    #[test]
+    fn relative_luminance() {
+        assert_eq!(Color::new(0, 0, 0).relative_luminance(), 0.0);
+        assert_eq!(Color::new(255, 255, 255).relative_luminance(), 1.0);
+        assert!((Color::from_hex(0xff0000).relative_luminance() - 0.2126).abs() < 1e-9);
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        assert_eq!(Color::BLACK.contrast_ratio(Color::WHITE), 21.0);
+        assert_eq!(Color::WHITE.contrast_ratio(Color::BLACK), 21.0);
+        assert_eq!(
+            Color::from_hex(0xef4444).contrast_ratio(Color::from_hex(0xef4444)),
+            1.0
+        );
+        assert!((Color::from_hex(0xef4444).contrast_ratio(Color::WHITE) - 3.76).abs() < 0.01);
+    }
+
+    #[test]
+    fn perceived_lightness() {
+        assert_eq!(Color::BLACK.perceived_lightness(), 0.0);
+        assert!((Color::WHITE.perceived_lightness() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn text_color() {
+        assert_eq!(Color::from_hex(0xb91c1c).text_color(), Color::WHITE);
+        assert_eq!(Color::from_hex(0xfef08a).text_color(), Color::BLACK);
+    }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::from_hex(0x4ade80).to_rgb(), (74, 222, 128));
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let color: Color = serde_yaml_ng::from_str("'rgb(255, 0, 0)'").unwrap();
+
+        assert_eq!(color, Color::from_hex(0xff0000));
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_color() {
+        let error = serde_yaml_ng::from_str::<Color>("'#12345'").unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("color `#12345` is not a hex code")
+        );
+    }
+
+    #[test]
+    fn trait_display() {
+        assert_eq!(Color::from_hex(0x4ade80).to_string(), "#4ade80");
+        assert_eq!(Color::new(0, 0, 0).to_string(), "#000000");
+    }
+
+    #[test]
+    fn trait_from_str_with_hex() {
+        assert_eq!(parse("#4ade80"), Color::from_hex(0x4ade80));
+        assert_eq!(parse("4ADE80"), Color::from_hex(0x4ade80));
+        assert_eq!(parse("#f00"), Color::from_hex(0xff0000));
+        assert_eq!(parse("f0a"), Color::from_hex(0xff00aa));
+    }
+
+    #[test]
+    fn trait_from_str_with_rgb() {
+        assert_eq!(parse("rgb(74, 222, 128)"), Color::from_hex(0x4ade80));
+        assert_eq!(parse("RGB(74 222 128)"), Color::from_hex(0x4ade80));
+        assert_eq!(parse("rgb(100%, 0%, 50%)"), Color::from_hex(0xff0080));
+    }
+
+    #[test]
+    fn trait_from_str_with_hsl() {
+        assert_eq!(parse("hsl(0, 100%, 50%)"), Color::from_hex(0xff0000));
+        assert_eq!(parse("hsl(120deg 100% 25%)"), Color::from_hex(0x008000));
+        assert_eq!(parse("hsl(-120, 100%, 50%)"), Color::from_hex(0x0000ff));
+    }
+
+    #[test]
+    fn trait_from_str_with_name() {
+        assert_eq!(parse("red"), Color::from_hex(0xff0000));
+        assert_eq!(parse("RebeccaPurple"), Color::from_hex(0x663399));
+        assert_eq!(parse(" slategrey "), Color::from_hex(0x708090));
+    }
+
+    #[test]
+    fn trait_from_str_with_invalid_color() {
+        let error = "#4ade8".parse::<Color>().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "color `#4ade8` is not a hex code like `#4ade80`, an `rgb()` or `hsl()` color, or a CSS color name"
+        );
+    }
+
+    #[test]
+    fn trait_from_str_with_invalid_components() {
+        let error = "rgb(300, 0, 0)".parse::<Color>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "color `rgb(300, 0, 0)` has the component `300`, which is not between 0 and 255 or 0% and 100%"
+        );
+
+        let error = "hsl(0, 100%)".parse::<Color>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "color `hsl(0, 100%)` must have three components like `hsl(142, 69%, 58%)`"
+        );
+    }
+
+    #[test]
+    fn trait_serialize() {
+        let serialized = serde_yaml_ng::to_string(&parse("F00")).unwrap();
+
+        assert_eq!(serialized, "'#ff0000'\n");
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Color>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Color>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Color>();
+    }
+}
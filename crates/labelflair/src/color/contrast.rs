@@ -0,0 +1,113 @@
+//! Contrast of colors with text
+//!
+//! This module defines the [`Contrast`] type, which measures how readable black and white text is
+//! on a color. The contrast ratios follow the [Web Content Accessibility Guidelines][wcag].
+//!
+//! [wcag]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+
+use getset::CopyGetters;
+
+use super::Color;
+
+/// The contrast of a color with black and white text
+///
+/// Each contrast ratio ranges from `1.0` for no contrast to `21.0` for black text on white. The
+/// Web Content Accessibility Guidelines require a ratio of at least `4.5` for normal text and `3.0`
+/// for large text.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, CopyGetters)]
+pub struct Contrast {
+    /// The color that is measured
+    #[getset(get_copy = "pub")]
+    color: Color,
+
+    /// The contrast ratio of the color with black text
+    #[getset(get_copy = "pub")]
+    black: f64,
+
+    /// The contrast ratio of the color with white text
+    #[getset(get_copy = "pub")]
+    white: f64,
+}
+
+impl Contrast {
+    /// Measure the contrast of a color with black and white text
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            black: color.contrast_ratio(Color::BLACK),
+            white: color.contrast_ratio(Color::WHITE),
+        }
+    }
+
+    /// Returns the text color with the higher contrast, either black or white
+    pub fn readable_text_color(&self) -> Color {
+        if self.black >= self.white {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+
+    /// Returns the contrast ratio of the text that GitHub draws on the color
+    ///
+    /// See [`Color::text_color`] for how GitHub picks the color of the text.
+    pub fn text(&self) -> f64 {
+        if self.color.text_color() == Color::WHITE {
+            self.white
+        } else {
+            self.black
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let contrast = Contrast::new(Color::WHITE);
+
+        assert_eq!(contrast.black(), 21.0);
+        assert_eq!(contrast.white(), 1.0);
+    }
+
+    #[test]
+    fn readable_text_color() {
+        assert_eq!(
+            Contrast::new(Color::from_hex(0xef4444)).readable_text_color(),
+            Color::BLACK
+        );
+        assert_eq!(
+            Contrast::new(Color::from_hex(0x1e3a8a)).readable_text_color(),
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn text() {
+        // GitHub draws white text on this red, although black text would be easier to read
+        let contrast = Contrast::new(Color::from_hex(0xef4444));
+
+        assert_eq!(contrast.text(), contrast.white());
+        assert!(contrast.text() < contrast.black());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Contrast>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Contrast>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Contrast>();
+    }
+}
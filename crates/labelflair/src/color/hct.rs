@@ -0,0 +1,456 @@
+//! Colors in the HCT color space
+//!
+//! This module defines the [`Hct`] type, which represents a color by its hue, chroma, and tone. HCT
+//! is the color space of Material Design 3. Its hue and chroma come from the CAM16 color appearance
+//! model, and its tone is the lightness `L*` of the CIELAB color space, which makes it easy to pick
+//! colors with a predictable contrast.
+//!
+//! See https://m3.material.io/blog/science-of-color-design for more information on the color space.
+
+use std::sync::LazyLock;
+
+use getset::CopyGetters;
+
+use super::Color;
+
+/// The largest difference between the requested tone and the tone of a color that is accepted
+const TONE_TOLERANCE: f64 = 0.2;
+
+/// The largest distance between a color and the requested hue that is accepted
+const HUE_TOLERANCE: f64 = 1.0;
+
+/// The precision of the search for the largest chroma that fits into the sRGB gamut
+const CHROMA_PRECISION: f64 = 0.4;
+
+/// The precision of the search for the CAM16 lightness of a color
+const LIGHTNESS_PRECISION: f64 = 0.01;
+
+/// The matrix that converts linear sRGB into the XYZ color space
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.41233895, 0.35762064, 0.18051042],
+    [0.2126, 0.7152, 0.0722],
+    [0.01932141, 0.11916382, 0.95034478],
+];
+
+/// The matrix that converts the XYZ color space into linear sRGB
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [
+        3.2413774792388685,
+        -1.5376652402851851,
+        -0.49885366846268053,
+    ],
+    [-0.9691452513005321, 1.8758853451067872, 0.04156585616912061],
+    [
+        0.05562093689691305,
+        -0.20395524564742123,
+        1.0571799111220335,
+    ],
+];
+
+/// The matrix that converts the XYZ color space into the cone responses of CAM16
+const XYZ_TO_CAM16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// The matrix that converts the cone responses of CAM16 into the XYZ color space
+const CAM16_TO_XYZ: [[f64; 3]; 3] = [
+    [1.86206786, -1.01125463, 0.14918677],
+    [0.38752654, 0.62144744, -0.00897398],
+    [-0.01584150, -0.03412294, 1.04996444],
+];
+
+/// The viewing conditions that Material Design uses for all colors
+static VIEWING_CONDITIONS: LazyLock<ViewingConditions> = LazyLock::new(ViewingConditions::new);
+
+/// A color in the HCT color space
+///
+/// The hue is measured in degrees in the range `0.0..360.0`, the chroma starts at `0.0` for gray and
+/// rarely exceeds `120.0`, and the tone is in the range `0.0..=100.0` from black to white.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, CopyGetters)]
+pub struct Hct {
+    /// The hue in degrees
+    #[getset(get_copy = "pub")]
+    hue: f64,
+
+    /// The chroma, which is `0.0` for gray
+    #[getset(get_copy = "pub")]
+    chroma: f64,
+
+    /// The tone between `0.0` for black and `100.0` for white
+    #[getset(get_copy = "pub")]
+    tone: f64,
+}
+
+/// The viewing conditions of the CAM16 color appearance model
+///
+/// Material Design assumes an average surround with a gray background, which is what the `new`
+/// method computes.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct ViewingConditions {
+    /// The ratio of the luminance of the background and the white point
+    n: f64,
+
+    /// The achromatic response of the white point
+    aw: f64,
+
+    /// The background induction factor
+    nbb: f64,
+
+    /// The exponent of the nonlinear response, which depends on the surround
+    c: f64,
+
+    /// The chromatic induction factor
+    nc: f64,
+
+    /// The factors of the chromatic adaptation to the white point
+    rgb_d: [f64; 3],
+
+    /// The luminance level adaptation factor
+    fl: f64,
+
+    /// The base exponential nonlinearity
+    z: f64,
+}
+
+/// A color in the CAM16 color appearance model
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Cam16 {
+    /// The lightness `J`
+    j: f64,
+
+    /// The chroma `C`
+    chroma: f64,
+
+    /// The hue in degrees
+    hue: f64,
+}
+
+impl Hct {
+    /// Create a new HCT color
+    ///
+    /// The hue is wrapped into the range `0.0..360.0`, the chroma cannot be negative, and the tone is
+    /// clamped to the range `0.0..=100.0`.
+    pub fn new(hue: f64, chroma: f64, tone: f64) -> Self {
+        Self {
+            hue: hue.rem_euclid(360.0),
+            chroma: chroma.max(0.0),
+            tone: tone.clamp(0.0, 100.0),
+        }
+    }
+
+    /// Converts the color into the sRGB color space
+    ///
+    /// Many HCT colors cannot be displayed on screens. For these colors, the chroma is reduced until
+    /// the color fits into the sRGB gamut, which keeps its hue and tone intact.
+    pub fn to_color(&self) -> Color {
+        if self.chroma < 1.0 || self.tone.round() <= 0.0 || self.tone.round() >= 100.0 {
+            return gray(self.tone);
+        }
+
+        if let Some(color) = self.find(self.chroma) {
+            return color;
+        }
+
+        let (mut low, mut high) = (0.0, self.chroma);
+        let mut answer = None;
+
+        while high - low >= CHROMA_PRECISION {
+            let chroma = (low + high) / 2.0;
+
+            match self.find(chroma) {
+                Some(color) => {
+                    answer = Some(color);
+                    low = chroma;
+                }
+                None => high = chroma,
+            }
+        }
+
+        answer.unwrap_or_else(|| gray(self.tone))
+    }
+
+    /// Find a color with the hue and tone of this color and the given chroma
+    ///
+    /// The CAM16 lightness of the color is searched until its tone matches. Returns `None` if the
+    /// color does not fit into the sRGB gamut.
+    fn find(&self, chroma: f64) -> Option<Color> {
+        let (mut low, mut high) = (0.0, 100.0);
+        let mut answer = None;
+
+        while high - low > LIGHTNESS_PRECISION {
+            let j = (low + high) / 2.0;
+            let color = Cam16::new(j, chroma, self.hue).to_color();
+            let tone = lstar(color);
+
+            if (self.tone - tone).abs() < TONE_TOLERANCE {
+                let cam = Cam16::from(color);
+
+                if cam.distance(&Cam16::new(cam.j, cam.chroma, self.hue)) <= HUE_TOLERANCE {
+                    answer = Some(color);
+                }
+            }
+
+            if tone < self.tone {
+                low = j;
+            } else {
+                high = j;
+            }
+        }
+
+        answer
+    }
+}
+
+impl From<Color> for Hct {
+    fn from(color: Color) -> Self {
+        let cam = Cam16::from(color);
+
+        Self::new(cam.hue, cam.chroma, lstar(color))
+    }
+}
+
+impl ViewingConditions {
+    /// Create the default viewing conditions of Material Design
+    fn new() -> Self {
+        let white = [95.047, 100.0, 108.883];
+        let adapting_luminance = 200.0 / std::f64::consts::PI * y_from_lstar(50.0) / 100.0;
+        let background_lstar: f64 = 50.0;
+        let surround = 2.0;
+
+        let rgb_w = multiply(&XYZ_TO_CAM16, white);
+
+        let f = 0.8 + surround / 10.0;
+        let c = if f >= 0.9 {
+            0.59 + (0.69 - 0.59) * ((f - 0.9) * 10.0)
+        } else {
+            0.525 + (0.59 - 0.525) * ((f - 0.8) * 10.0)
+        };
+        let d =
+            (f * (1.0 - (1.0 / 3.6) * ((-adapting_luminance - 42.0) / 92.0).exp())).clamp(0.0, 1.0);
+        let rgb_d = rgb_w.map(|component| d * (100.0 / component) + 1.0 - d);
+
+        let k = 1.0 / (5.0 * adapting_luminance + 1.0);
+        let k4 = k.powi(4);
+        let k4f = 1.0 - k4;
+        let fl = k4 * adapting_luminance + 0.1 * k4f * k4f * (5.0 * adapting_luminance).cbrt();
+
+        let n = y_from_lstar(background_lstar.max(0.1)) / white[1];
+        let z = 1.48 + n.sqrt();
+        let nbb = 0.725 / n.powf(0.2);
+
+        let rgb_a = [0, 1, 2].map(|index| {
+            let factor = (fl * rgb_d[index] * rgb_w[index] / 100.0).powf(0.42);
+
+            400.0 * factor / (factor + 27.13)
+        });
+        let aw = (2.0 * rgb_a[0] + rgb_a[1] + 0.05 * rgb_a[2]) * nbb;
+
+        Self {
+            n,
+            aw,
+            nbb,
+            c,
+            nc: f,
+            rgb_d,
+            fl,
+            z,
+        }
+    }
+
+    /// Returns the eccentricity factor of a hue in degrees
+    fn eccentricity(hue: f64) -> f64 {
+        0.25 * ((hue.to_radians() + 2.0).cos() + 3.8)
+    }
+}
+
+impl Cam16 {
+    /// Create a new CAM16 color from its lightness, chroma, and hue
+    fn new(j: f64, chroma: f64, hue: f64) -> Self {
+        Self { j, chroma, hue }
+    }
+
+    /// Converts the color into the sRGB color space, clamping components outside of the gamut
+    fn to_color(self) -> Color {
+        let vc = &*VIEWING_CONDITIONS;
+
+        let alpha = if self.chroma == 0.0 || self.j == 0.0 {
+            0.0
+        } else {
+            self.chroma / (self.j / 100.0).sqrt()
+        };
+        let t = (alpha / (1.64 - 0.29_f64.powf(vc.n)).powf(0.73)).powf(1.0 / 0.9);
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+
+        let ac = vc.aw * (self.j / 100.0).powf(1.0 / vc.c / vc.z);
+        let p1 = ViewingConditions::eccentricity(self.hue) * (50000.0 / 13.0) * vc.nc * vc.nbb;
+        let p2 = ac / vc.nbb;
+
+        let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * cos + 108.0 * t * sin);
+        let (a, b) = (gamma * cos, gamma * sin);
+
+        let rgb_a = [
+            (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0,
+            (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0,
+            (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0,
+        ];
+        let rgb_f = [0, 1, 2].map(|index| {
+            let component = rgb_a[index];
+            let base = (27.13 * component.abs() / (400.0 - component.abs())).max(0.0);
+
+            component.signum() * (100.0 / vc.fl) * base.powf(1.0 / 0.42) / vc.rgb_d[index]
+        });
+
+        let xyz = multiply(&CAM16_TO_XYZ, rgb_f);
+
+        Color::from_linear(multiply(&XYZ_TO_SRGB, xyz).map(|component| component / 100.0))
+    }
+
+    /// Returns the distance between two colors in the CAM16-UCS color space
+    fn distance(&self, other: &Self) -> f64 {
+        let [j1, a1, b1] = self.ucs();
+        let [j2, a2, b2] = other.ucs();
+
+        let distance = ((j1 - j2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt();
+
+        1.41 * distance.powf(0.63)
+    }
+
+    /// Returns the coordinates of the color in the CAM16-UCS color space
+    fn ucs(&self) -> [f64; 3] {
+        let colorfulness = self.chroma * VIEWING_CONDITIONS.fl.powf(0.25);
+        let m = (1.0 + 0.0228 * colorfulness).ln() / 0.0228;
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+
+        [
+            (1.0 + 100.0 * 0.007) * self.j / (1.0 + 0.007 * self.j),
+            m * cos,
+            m * sin,
+        ]
+    }
+}
+
+impl From<Color> for Cam16 {
+    fn from(color: Color) -> Self {
+        let vc = &*VIEWING_CONDITIONS;
+
+        let linear = color.to_linear().map(|component| component * 100.0);
+        let cone = multiply(&XYZ_TO_CAM16, multiply(&SRGB_TO_XYZ, linear));
+
+        let [r, g, b] = [0, 1, 2].map(|index| {
+            let component = vc.rgb_d[index] * cone[index];
+            let factor = (vc.fl * component.abs() / 100.0).powf(0.42);
+
+            component.signum() * 400.0 * factor / (factor + 27.13)
+        });
+
+        let a = (11.0 * r - 12.0 * g + b) / 11.0;
+        let b_ = (r + g - 2.0 * b) / 9.0;
+        let u = (20.0 * r + 20.0 * g + 21.0 * b) / 20.0;
+        let p2 = (40.0 * r + 20.0 * g + b) / 20.0;
+
+        let hue = b_.atan2(a).to_degrees().rem_euclid(360.0);
+        let ac = p2 * vc.nbb;
+        let j = 100.0 * (ac / vc.aw).powf(vc.c * vc.z);
+
+        let p1 = 50000.0 / 13.0 * ViewingConditions::eccentricity(hue) * vc.nc * vc.nbb;
+        let t = p1 * a.hypot(b_) / (u + 0.305);
+        let alpha = t.powf(0.9) * (1.64 - 0.29_f64.powf(vc.n)).powf(0.73);
+
+        Self::new(j, alpha * (j / 100.0).sqrt(), hue)
+    }
+}
+
+/// Multiply a 3x3 matrix with a vector
+fn multiply(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Returns the lightness `L*` of a color in the CIELAB color space
+fn lstar(color: Color) -> f64 {
+    let y = color.relative_luminance();
+
+    let f = if y > 216.0 / 24389.0 {
+        y.cbrt()
+    } else {
+        (24389.0 / 27.0 * y + 16.0) / 116.0
+    };
+
+    116.0 * f - 16.0
+}
+
+/// Returns the luminance `Y` between `0.0` and `100.0` for a lightness `L*`
+fn y_from_lstar(lstar: f64) -> f64 {
+    let f = (lstar + 16.0) / 116.0;
+    let cube = f * f * f;
+
+    if cube > 216.0 / 24389.0 {
+        cube * 100.0
+    } else {
+        (116.0 * f - 16.0) / (24389.0 / 27.0) * 100.0
+    }
+}
+
+/// Returns the gray with the given tone
+fn gray(tone: f64) -> Color {
+    let y = y_from_lstar(tone) / 100.0;
+
+    Color::from_linear([y, y, y])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_from_color() {
+        let hct = Hct::from(Color::from_hex(0x0000ff));
+
+        assert!((hct.hue() - 282.79).abs() < 0.1);
+        assert!((hct.chroma() - 87.23).abs() < 0.1);
+        assert!((hct.tone() - 32.30).abs() < 0.1);
+    }
+
+    #[test]
+    fn to_color_round_trip() {
+        for hex in [0x6750a4, 0xb3261e, 0x0061a4, 0x386a20] {
+            let color = Color::from_hex(hex);
+
+            assert_eq!(color.to_hct().to_color(), color);
+        }
+    }
+
+    #[test]
+    fn to_color_keeps_tone() {
+        let color = Hct::new(282.79, 87.23, 90.0).to_color();
+        let hct = color.to_hct();
+
+        assert!((hct.tone() - 90.0).abs() < 0.5);
+        assert!((hct.hue() - 282.79).abs() < 3.0);
+    }
+
+    #[test]
+    fn to_color_for_gray() {
+        assert_eq!(Hct::new(0.0, 0.0, 100.0).to_color(), Color::WHITE);
+        assert_eq!(Hct::new(0.0, 0.0, 0.0).to_color(), Color::BLACK);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Hct>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Hct>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Hct>();
+    }
+}
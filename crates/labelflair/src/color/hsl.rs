@@ -0,0 +1,151 @@
+//! Colors in the HSL color space
+//!
+//! This module defines the [`Hsl`] type, which represents a color by its hue, saturation, and
+//! lightness. It is the color space of the CSS function `hsl()`.
+
+use getset::CopyGetters;
+
+use super::Color;
+
+/// A color in the HSL color space
+///
+/// The hue is measured in degrees in the range `0.0..360.0`, while the saturation and lightness are
+/// in the range `0.0..=1.0`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, CopyGetters)]
+pub struct Hsl {
+    /// The hue in degrees
+    #[getset(get_copy = "pub")]
+    hue: f64,
+
+    /// The saturation between `0.0` and `1.0`
+    #[getset(get_copy = "pub")]
+    saturation: f64,
+
+    /// The lightness between `0.0` and `1.0`
+    #[getset(get_copy = "pub")]
+    lightness: f64,
+}
+
+impl Hsl {
+    /// Create a new HSL color
+    ///
+    /// The hue is wrapped into the range `0.0..360.0`, and the saturation and lightness are clamped
+    /// to the range `0.0..=1.0`.
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue: hue.rem_euclid(360.0),
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness: lightness.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Converts the color into the sRGB color space
+    pub fn to_color(&self) -> Color {
+        let chroma = (1.0 - (2.0 * self.lightness - 1.0).abs()) * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (red, green, blue) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let offset = self.lightness - chroma / 2.0;
+        let byte = |value: f64| super::to_byte(value + offset);
+
+        Color::new(byte(red), byte(green), byte(blue))
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let [red, green, blue] =
+            [color.red, color.green, color.blue].map(|component| f64::from(component) / 255.0);
+
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let lightness = (max + min) / 2.0;
+
+        if delta == 0.0 {
+            return Self::new(0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            60.0 * ((green - blue) / delta)
+        } else if max == green {
+            60.0 * ((blue - red) / delta + 2.0)
+        } else {
+            60.0 * ((red - green) / delta + 4.0)
+        };
+
+        Self::new(hue, saturation, lightness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_wraps_hue() {
+        let hsl = Hsl::new(-90.0, 1.5, -0.5);
+
+        assert_eq!(hsl, Hsl::new(270.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn to_color() {
+        assert_eq!(
+            Hsl::new(0.0, 1.0, 0.5).to_color(),
+            Color::from_hex(0xff0000)
+        );
+        assert_eq!(
+            Hsl::new(240.0, 1.0, 0.5).to_color(),
+            Color::from_hex(0x0000ff)
+        );
+        assert_eq!(
+            Hsl::new(0.0, 0.0, 1.0).to_color(),
+            Color::from_hex(0xffffff)
+        );
+    }
+
+    #[test]
+    fn trait_from_color() {
+        let hsl = Hsl::from(Color::from_hex(0x00ff00));
+
+        assert_eq!(hsl, Hsl::new(120.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn round_trip() {
+        for hex in [0x4ade80, 0xb91c1c, 0x1e3a8a, 0x737373, 0xfef9c3] {
+            let color = Color::from_hex(hex);
+
+            assert_eq!(color.to_hsl().to_color(), color);
+        }
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Hsl>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Hsl>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Hsl>();
+    }
+}
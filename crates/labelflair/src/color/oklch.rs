@@ -0,0 +1,175 @@
+//! Colors in the OKLCH color space
+//!
+//! This module defines the [`Oklch`] type, which represents a color by its perceived lightness,
+//! chroma, and hue. Unlike HSL, changing one of the components of an OKLCH color does not change
+//! how the other components are perceived, which makes it a good color space to generate palettes.
+//!
+//! See https://bottosson.github.io/posts/oklab/ for more information on the color space.
+
+use getset::CopyGetters;
+
+use super::Color;
+
+/// The number of iterations of the binary search that maps colors into the sRGB gamut
+const GAMUT_ITERATIONS: usize = 24;
+
+/// The tolerance for components in linear light that are slightly outside of the sRGB gamut
+const GAMUT_TOLERANCE: f64 = 1e-6;
+
+/// A color in the OKLCH color space
+///
+/// The lightness is in the range `0.0..=1.0`, the chroma starts at `0.0` for gray and rarely
+/// exceeds `0.4`, and the hue is measured in degrees in the range `0.0..360.0`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, CopyGetters)]
+pub struct Oklch {
+    /// The perceived lightness between `0.0` and `1.0`
+    #[getset(get_copy = "pub")]
+    lightness: f64,
+
+    /// The chroma, which is `0.0` for gray
+    #[getset(get_copy = "pub")]
+    chroma: f64,
+
+    /// The hue in degrees
+    #[getset(get_copy = "pub")]
+    hue: f64,
+}
+
+impl Oklch {
+    /// Create a new OKLCH color
+    ///
+    /// The lightness is clamped to the range `0.0..=1.0`, the chroma cannot be negative, and the hue
+    /// is wrapped into the range `0.0..360.0`.
+    pub fn new(lightness: f64, chroma: f64, hue: f64) -> Self {
+        Self {
+            lightness: lightness.clamp(0.0, 1.0),
+            chroma: chroma.max(0.0),
+            hue: hue.rem_euclid(360.0),
+        }
+    }
+
+    /// Converts the color into the sRGB color space
+    ///
+    /// Many OKLCH colors cannot be displayed on screens. For these colors, the chroma is reduced
+    /// until the color fits into the sRGB gamut, which keeps its lightness and hue intact.
+    pub fn to_color(&self) -> Color {
+        if in_gamut(self.to_linear(self.chroma)) {
+            return Color::from_linear(self.to_linear(self.chroma));
+        }
+
+        let (mut low, mut high) = (0.0, self.chroma);
+
+        for _ in 0..GAMUT_ITERATIONS {
+            let chroma = (low + high) / 2.0;
+
+            if in_gamut(self.to_linear(chroma)) {
+                low = chroma;
+            } else {
+                high = chroma;
+            }
+        }
+
+        Color::from_linear(self.to_linear(low))
+    }
+
+    /// Returns the components of the color with the given chroma in linear sRGB
+    fn to_linear(self, chroma: f64) -> [f64; 3] {
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        let (l, a, b) = (self.lightness, chroma * cos, chroma * sin);
+
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+        let (l, m, s) = (l_.powi(3), m_.powi(3), s_.powi(3));
+
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    }
+}
+
+/// Returns `true` if the components in linear light can be displayed in sRGB
+fn in_gamut(components: [f64; 3]) -> bool {
+    components
+        .iter()
+        .all(|component| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(component))
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        let [red, green, blue] = color.to_linear();
+
+        let l = 0.4122214708 * red + 0.5363325363 * green + 0.0514459929 * blue;
+        let m = 0.2119034982 * red + 0.6806995451 * green + 0.1073969566 * blue;
+        let s = 0.0883024619 * red + 0.2817188376 * green + 0.6299787005 * blue;
+
+        let (l_, m_, s_) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        let lightness = 0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_;
+        let a = 1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_;
+        let b = 0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_;
+
+        Self::new(lightness, a.hypot(b), b.atan2(a).to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_color_reduces_chroma_outside_of_gamut() {
+        let color = Oklch::new(0.9, 0.4, 30.0).to_color();
+        let oklch = color.to_oklch();
+
+        assert!((oklch.lightness() - 0.9).abs() < 0.01);
+        assert!((oklch.hue() - 30.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn trait_from_color() {
+        let oklch = Oklch::from(Color::from_hex(0xffffff));
+
+        assert!((oklch.lightness() - 1.0).abs() < 1e-6);
+        assert!(oklch.chroma() < 1e-6);
+    }
+
+    #[test]
+    fn trait_from_color_for_red() {
+        let oklch = Oklch::from(Color::from_hex(0xff0000));
+
+        assert!((oklch.lightness() - 0.628).abs() < 1e-3);
+        assert!((oklch.chroma() - 0.2577).abs() < 1e-3);
+        assert!((oklch.hue() - 29.23).abs() < 0.1);
+    }
+
+    #[test]
+    fn round_trip() {
+        for hex in [0x4ade80, 0xb91c1c, 0x1e3a8a, 0x737373, 0xfef9c3, 0x000000] {
+            let color = Color::from_hex(hex);
+
+            assert_eq!(color.to_oklch().to_color(), color);
+        }
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Oklch>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Oklch>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Oklch>();
+    }
+}
@@ -4,30 +4,154 @@
 //! [`Generate`] trait, which returns a list of [`Color`] based on its internal logic. All available
 //! generators are listed in the [`Colors`] enum.
 
-use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Formatter;
 
-use crate::label::Color;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::de::{Error as _, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 
+use crate::Error;
+use crate::color::Color;
+
+pub use self::distinct::Distinct;
 pub use self::fixed::Fixed;
-pub use self::tailwind::Tailwind;
+pub use self::gradient::{Gradient, Interpolation};
+pub use self::material::Material;
+pub use self::oklch::OklchScale;
+pub use self::open_color::OpenColor;
+pub use self::palette::{Palette, PaletteName, PaletteRef};
+pub use self::primer::PrimerScale;
+pub use self::radix::Radix;
+pub use self::tailwind::{Order, Shade, Tailwind, TailwindScale, TailwindVersion};
+
+/// Implement equality, ordering, and hashing for a color generator with floating-point settings
+///
+/// Floating-point numbers implement neither `Eq` nor `Hash`, so the generator returns its settings
+/// from a `components` method. The settings are compared with `f64::total_cmp` and hashed by their
+/// bits, which makes equal settings compare and hash the same.
+macro_rules! float_components {
+    ($name:ident) => {
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == std::cmp::Ordering::Equal
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.components()
+                    .iter()
+                    .zip(other.components().iter())
+                    .map(|(a, b)| a.total_cmp(b))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }
+        }
 
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.components().map(f64::to_bits).hash(state);
+            }
+        }
+    };
+}
+
+mod distinct;
 mod fixed;
+mod gradient;
+mod material;
+mod oklch;
+mod open_color;
+mod palette;
+mod primer;
+mod radix;
 mod tailwind;
 
 /// Color generators in Labelflair
 ///
 /// This enum represents different color generators available in Labelflair. Each generator has its
 /// own logic for generating colors, so read their documentation for more details.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
+///
+/// In the configuration, the generator is picked by its key, e.g. `{ tailwind = "red" }`. The
+/// Tailwind generator accepts the options `shades`, `range`, `order`, and `version` next to its key.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Colors {
+    /// Give every label a different hue
+    Distinct(Distinct),
+
     /// Use a fixed color for all labels
     Fixed(Fixed),
 
+    /// Interpolate between two or more anchor colors
+    Gradient(Gradient),
+
+    /// Use a tonal palette of Material Design 3 that is computed from a seed color
+    Material(Material),
+
+    /// Generate evenly spaced shades of a hue in the OKLCH color space
+    Oklch(OklchScale),
+
+    /// Use the color palette from Open Color
+    OpenColor(OpenColor),
+
+    /// Use a palette that is defined in the configuration
+    Palette(PaletteRef),
+
+    /// Use a base color scale from GitHub's Primer design system
+    Primer(PrimerScale),
+
+    /// Use the color palette from Radix Colors
+    Radix(Radix),
+
     /// Use the color palette from Tailwind CSS
-    Tailwind(Tailwind),
+    Tailwind(TailwindScale),
 }
 
+/// The keys that pick a color generator
+const GENERATORS: [&str; 10] = [
+    "distinct",
+    "fixed",
+    "gradient",
+    "material",
+    "oklch",
+    "open-color",
+    "palette",
+    "primer",
+    "radix",
+    "tailwind",
+];
+
+/// The keys that are accepted in the table of a color generator
+const KEYS: [&str; 14] = [
+    "distinct",
+    "fixed",
+    "gradient",
+    "material",
+    "oklch",
+    "open-color",
+    "palette",
+    "primer",
+    "radix",
+    "tailwind",
+    "shades",
+    "range",
+    "order",
+    "version",
+];
+
+/// Visitor that reads a color generator and its options from a table
+struct ColorsVisitor;
+
 /// Trait for color generators
 ///
 /// This trait defines the interface for color generators in Labelflair. Each generator must
@@ -40,23 +164,385 @@ pub trait Generate {
     fn generate(&self, count: usize) -> Vec<Color>;
 }
 
-impl Generate for Colors {
-    fn generate(&self, count: usize) -> Vec<Color> {
+impl Colors {
+    /// Generate a list of colors
+    ///
+    /// Unlike the generators, `Colors` can reference a palette in the configuration, which is
+    /// looked up in `palettes`. Returns [`Error::UnknownPalette`] if the palette does not exist.
+    pub fn generate(
+        &self,
+        count: usize,
+        palettes: &BTreeMap<PaletteName, Palette>,
+    ) -> Result<Vec<Color>, Error> {
         let variant: Box<&dyn Generate> = match self {
+            Colors::Distinct(distinct) => Box::new(distinct),
             Colors::Fixed(fixed) => Box::new(fixed),
+            Colors::Gradient(gradient) => Box::new(gradient),
+            Colors::Material(material) => Box::new(material),
+            Colors::Oklch(oklch) => Box::new(oklch),
+            Colors::OpenColor(open_color) => Box::new(open_color),
+            Colors::Palette(reference) => Box::new(reference.resolve(palettes)?),
+            Colors::Primer(primer) => Box::new(primer),
+            Colors::Radix(radix) => Box::new(radix),
             Colors::Tailwind(tailwind) => Box::new(tailwind),
         };
 
-        variant.generate(count)
+        Ok(variant.generate(count))
+    }
+
+    /// Returns whether the labels of a group are sorted by name before the colors are assigned
+    ///
+    /// The Tailwind generator without an `order` sorts the labels, so that its shades go from light
+    /// to dark in the list of labels on GitHub, which is sorted by name as well. All other
+    /// generators assign their colors in the order of the labels in the configuration, e.g. the
+    /// first anchor of a gradient to the first label.
+    pub fn sorts_labels(&self) -> bool {
+        matches!(self, Colors::Tailwind(scale) if scale.order().is_none())
+    }
+}
+
+impl JsonSchema for Colors {
+    fn schema_name() -> Cow<'static, str> {
+        "Colors".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let variant = |key: &str, description: &str, schema: Schema| {
+            json_schema!({
+                "type": "object",
+                "description": description,
+                "properties": { key: schema },
+                "required": [key],
+                "additionalProperties": false,
+            })
+        };
+
+        json_schema!({
+            "description": "A color generator, which is picked by its key",
+            "oneOf": [
+                variant("distinct", "Give every label a different hue", generator.subschema_for::<Distinct>()),
+                variant("fixed", "Use a fixed color for all labels", generator.subschema_for::<Fixed>()),
+                variant("gradient", "Interpolate between two or more anchor colors", generator.subschema_for::<Gradient>()),
+                variant("material", "Use a tonal palette of Material Design 3 that is computed from a seed color", generator.subschema_for::<Material>()),
+                variant("oklch", "Generate evenly spaced shades of a hue in the OKLCH color space", generator.subschema_for::<OklchScale>()),
+                variant("open-color", "Use the color palette from Open Color", generator.subschema_for::<OpenColor>()),
+                variant("palette", "Use a palette that is defined in the configuration", generator.subschema_for::<PaletteRef>()),
+                variant("primer", "Use a base color scale from GitHub's Primer design system", generator.subschema_for::<PrimerScale>()),
+                variant("radix", "Use the color palette from Radix Colors", generator.subschema_for::<Radix>()),
+                {
+                    "type": "object",
+                    "description": "Use the color palette from Tailwind CSS",
+                    "properties": {
+                        "tailwind": generator.subschema_for::<Tailwind>(),
+                        "shades": {
+                            "description": "An explicit list of shades to pick from",
+                            "type": "array",
+                            "items": generator.subschema_for::<Shade>(),
+                            "minItems": 1,
+                        },
+                        "range": {
+                            "description": "The lightest and the darkest shade to pick from",
+                            "type": "array",
+                            "items": generator.subschema_for::<Shade>(),
+                            "minItems": 2,
+                            "maxItems": 2,
+                        },
+                        "order": generator.subschema_for::<Order>(),
+                        "version": generator.subschema_for::<TailwindVersion>(),
+                    },
+                    "required": ["tailwind"],
+                    "additionalProperties": false,
+                },
+            ],
+        })
     }
 }
 
+impl<'de> Deserialize<'de> for Colors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ColorsVisitor)
+    }
+}
+
+impl<'de> Visitor<'de> for ColorsVisitor {
+    type Value = Colors;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a color generator like `{ tailwind = \"red\" }`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut generators: Vec<(String, Option<Colors>)> = Vec::new();
+        let mut tailwind: Option<Tailwind> = None;
+        let mut shades: Option<Vec<Shade>> = None;
+        let mut range: Option<[Shade; 2]> = None;
+        let mut order: Option<Order> = None;
+        let mut version: Option<TailwindVersion> = None;
+        let mut options: Vec<String> = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "distinct" => generators.push((key, Some(Colors::Distinct(map.next_value()?)))),
+                "fixed" => generators.push((key, Some(Colors::Fixed(map.next_value()?)))),
+                "gradient" => generators.push((key, Some(Colors::Gradient(map.next_value()?)))),
+                "material" => generators.push((key, Some(Colors::Material(map.next_value()?)))),
+                "oklch" => generators.push((key, Some(Colors::Oklch(map.next_value()?)))),
+                "open-color" => {
+                    generators.push((key, Some(Colors::OpenColor(map.next_value()?))));
+                }
+                "palette" => generators.push((key, Some(Colors::Palette(map.next_value()?)))),
+                "primer" => generators.push((key, Some(Colors::Primer(map.next_value()?)))),
+                "radix" => generators.push((key, Some(Colors::Radix(map.next_value()?)))),
+                "tailwind" => {
+                    tailwind = Some(map.next_value()?);
+                    generators.push((key, None));
+                }
+                "shades" => {
+                    shades = Some(map.next_value()?);
+                    options.push(key);
+                }
+                "range" => {
+                    range = Some(map.next_value()?);
+                    options.push(key);
+                }
+                "order" => {
+                    order = Some(map.next_value()?);
+                    options.push(key);
+                }
+                "version" => {
+                    version = Some(map.next_value()?);
+                    options.push(key);
+                }
+                _ => return Err(A::Error::unknown_field(&key, &KEYS)),
+            }
+        }
+
+        let (key, colors) = match generators.len() {
+            0 => {
+                return Err(A::Error::custom(format!(
+                    "missing color generator, expected one of {}",
+                    GENERATORS.join(", ")
+                )));
+            }
+            1 => generators.remove(0),
+            _ => {
+                let keys: Vec<String> = generators
+                    .into_iter()
+                    .map(|(key, _)| format!("`{key}`"))
+                    .collect();
+
+                return Err(A::Error::custom(format!(
+                    "expected a single color generator, but found {}",
+                    keys.join(" and ")
+                )));
+            }
+        };
+
+        if let Some(colors) = colors {
+            return match options.first() {
+                Some(option) => Err(A::Error::custom(format!(
+                    "`{option}` can only be used with the `tailwind` color generator, not with `{key}`"
+                ))),
+                None => Ok(colors),
+            };
+        }
+
+        let color = tailwind.expect("the tailwind key sets the color");
+
+        if shades.is_some() && range.is_some() {
+            return Err(A::Error::custom(
+                "`shades` and `range` cannot be used together",
+            ));
+        }
+
+        if shades.as_ref().is_some_and(Vec::is_empty) {
+            return Err(A::Error::custom("`shades` must list at least one shade"));
+        }
+
+        if let Some([lightest, darkest]) = range {
+            if lightest > darkest {
+                return Err(A::Error::custom(format!(
+                    "`range` must start with the lighter shade, but {} is darker than {}",
+                    lightest.get(),
+                    darkest.get()
+                )));
+            }
+        }
+
+        Ok(Colors::Tailwind(
+            TailwindScale::builder()
+                .color(color)
+                .shades(shades)
+                .range(range)
+                .order(order)
+                .version(version.unwrap_or_default())
+                .build(),
+        ))
+    }
+}
+
+/// Pick evenly spaced shades from a palette for the given number of labels
+///
+/// See [`centered_indices`] for how the shades are picked. Returns an empty list if the palette does
+/// not have any shades.
+fn spread(count: usize, shades: &[Color]) -> Vec<Color> {
+    if shades.is_empty() {
+        return Vec::new();
+    }
+
+    centered_indices(count, shades.len())
+        .into_iter()
+        .map(|index| shades[index])
+        .collect()
+}
+
+/// Check that a hue in the configuration is a finite number of degrees
+fn check_hue(hue: f64) -> Result<(), String> {
+    if hue.is_finite() {
+        Ok(())
+    } else {
+        Err(format!("hue must be a finite number, but got {hue}"))
+    }
+}
+
+/// Check that a chroma in the configuration is not negative
+fn check_chroma(chroma: f64) -> Result<(), String> {
+    if chroma >= 0.0 && chroma.is_finite() {
+        Ok(())
+    } else {
+        Err(format!("chroma must be at least 0, but got {chroma}"))
+    }
+}
+
+/// Check that a lightness in the configuration is between 0 and 1
+fn check_lightness(lightness: f64) -> Result<(), String> {
+    if (0.0..=1.0).contains(&lightness) {
+        Ok(())
+    } else {
+        Err(format!(
+            "lightness must be between 0 and 1, but got {lightness}"
+        ))
+    }
+}
+
+/// Generate a list of evenly spaced indices into a palette with the given number of shades
+///
+/// If the requested count is less than the number of shades, this function spaces the picked
+/// indices evenly across the palette. For example, with 9 shades and count=3 it returns
+/// `[1, 4, 7]`.
+///
+/// If more colors are requested than available shades, the indices will simply repeat
+/// the palette from the beginning: `[0, 1, 2, ...]`.
+fn centered_indices(count: usize, shades: usize) -> Vec<usize> {
+    // If the count is zero, exit early and return an empty vector
+    if count == 0 {
+        return Vec::new();
+    }
+
+    // If more colors are requested than available shades, repeat the palette from the start
+    if count > shades {
+        return (0..count).map(|i| i % shades).collect();
+    }
+
+    // Otherwise, distribute picks evenly across the palette using
+    // floor((k + 0.5) * N / M), implemented as integer math: ((2*k + 1) * N) / (2*M)
+    (0..count)
+        .map(|k| ((2 * k + 1) * shades) / (2 * count))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
 
     use super::*;
 
+    #[test]
+    fn centered_indices_for_1() {
+        let indices = centered_indices(1, 9);
+
+        assert_eq!(indices, vec![4]);
+    }
+
+    #[test]
+    fn centered_indices_for_2() {
+        let indices = centered_indices(2, 9);
+
+        assert_eq!(indices, vec![2, 6]);
+    }
+
+    #[test]
+    fn centered_indices_for_3() {
+        let indices = centered_indices(3, 9);
+
+        assert_eq!(indices, vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn centered_indices_for_4() {
+        let indices = centered_indices(4, 9);
+
+        assert_eq!(indices, vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn centered_indices_for_5() {
+        let indices = centered_indices(5, 9);
+
+        assert_eq!(indices, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn centered_indices_for_6() {
+        let indices = centered_indices(6, 9);
+
+        assert_eq!(indices, vec![0, 2, 3, 5, 6, 8]);
+    }
+
+    #[test]
+    fn centered_indices_for_7() {
+        let indices = centered_indices(7, 9);
+
+        assert_eq!(indices, vec![0, 1, 3, 4, 5, 7, 8]);
+    }
+
+    #[test]
+    fn centered_indices_for_8() {
+        let indices = centered_indices(8, 9);
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn centered_indices_for_9() {
+        let indices = centered_indices(9, 9);
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn centered_indices_for_10() {
+        let indices = centered_indices(10, 9);
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 0]);
+    }
+
+    #[test]
+    fn centered_indices_for_18() {
+        let indices = centered_indices(18, 9);
+
+        assert_eq!(
+            indices,
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 0, 1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
     #[test]
     fn trait_deserialize_tailwind() {
         let toml = indoc! {r#"
@@ -65,7 +551,76 @@ mod tests {
 
         let colors: Colors = toml::from_str(toml).unwrap();
 
-        assert_eq!(Colors::Tailwind(Tailwind::Red), colors);
+        assert_eq!(Colors::Tailwind(Tailwind::Red.into()), colors);
+    }
+
+    #[test]
+    fn trait_deserialize_tailwind_with_options() {
+        let toml = indoc! {r#"
+            tailwind = "red"
+            shades = [300, 500, 700]
+            order = "dark-to-light"
+        "#};
+
+        let colors: Colors = toml::from_str(toml).unwrap();
+        let expected = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .shades(Some(vec![
+                Shade::new(300).unwrap(),
+                Shade::new(500).unwrap(),
+                Shade::new(700).unwrap(),
+            ]))
+            .order(Order::DarkToLight)
+            .build();
+
+        assert_eq!(colors, Colors::Tailwind(expected));
+    }
+
+    #[test]
+    fn trait_deserialize_tailwind_with_range() {
+        let colors: Colors = toml::from_str("tailwind = \"red\"\nrange = [200, 600]").unwrap();
+        let expected = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .range(Some([Shade::new(200).unwrap(), Shade::new(600).unwrap()]))
+            .build();
+
+        assert_eq!(colors, Colors::Tailwind(expected));
+    }
+
+    #[test]
+    fn trait_deserialize_tailwind_with_version() {
+        let colors: Colors = toml::from_str("tailwind = \"neutral\"\nversion = 4").unwrap();
+        let expected = TailwindScale::builder()
+            .color(Tailwind::Neutral)
+            .version(TailwindVersion::V4)
+            .build();
+
+        assert_eq!(colors, Colors::Tailwind(expected));
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_options() {
+        let error = |toml: &str| toml::from_str::<Colors>(toml).unwrap_err().to_string();
+
+        assert!(error("fixed = \"#ff0000\"\nshades = [300]").contains(
+            "`shades` can only be used with the `tailwind` color generator, not with `fixed`"
+        ));
+        assert!(
+            error("tailwind = \"red\"\nshades = [300]\nrange = [200, 600]")
+                .contains("`shades` and `range` cannot be used together")
+        );
+        assert!(
+            error("tailwind = \"red\"\nrange = [600, 200]")
+                .contains("`range` must start with the lighter shade, but 600 is darker than 200")
+        );
+        assert!(error("oklch = { hue = 250 }\nversion = 4").contains(
+            "`version` can only be used with the `tailwind` color generator, not with `oklch`"
+        ));
+        assert!(error("order = \"alternating\"").contains("missing color generator"));
+        assert!(
+            error("fixed = \"#ff0000\"\ntailwind = \"red\"")
+                .contains("expected a single color generator, but found `fixed` and `tailwind`")
+        );
     }
 
     #[test]
@@ -76,7 +631,67 @@ mod tests {
 
         let colors: Colors = toml::from_str(toml).unwrap();
 
-        assert_eq!(colors, Colors::Fixed(Fixed::new(Color::new("#0000FF"))));
+        assert_eq!(colors, Colors::Fixed(Fixed::new(Color::from_hex(0x0000ff))));
+    }
+
+    #[test]
+    fn trait_deserialize_distinct() {
+        let toml = indoc! {r#"
+            distinct = { chroma = 0.1, lightness = 0.65 }
+        "#};
+
+        let colors: Colors = toml::from_str(toml).unwrap();
+
+        assert_eq!(colors, Colors::Distinct(Distinct::new(0.0, 0.1, 0.65)));
+    }
+
+    #[test]
+    fn trait_deserialize_gradient() {
+        let toml = indoc! {r##"
+            gradient = ["#fde68a", "#b91c1c"]
+        "##};
+
+        let colors: Colors = toml::from_str(toml).unwrap();
+        let expected = Gradient::new(
+            vec![Color::from_hex(0xfde68a), Color::from_hex(0xb91c1c)],
+            Interpolation::Oklab,
+        )
+        .unwrap();
+
+        assert_eq!(colors, Colors::Gradient(expected));
+    }
+
+    #[test]
+    fn trait_deserialize_oklch() {
+        let toml = indoc! {r#"
+            oklch = { hue = 250, chroma = 0.12, lightness = [0.45, 0.85] }
+        "#};
+
+        let colors: Colors = toml::from_str(toml).unwrap();
+
+        assert_eq!(
+            colors,
+            Colors::Oklch(OklchScale::new(250.0, 0.12, [0.45, 0.85]))
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_design_systems() {
+        let colors = |toml: &str| toml::from_str::<Colors>(toml).unwrap();
+
+        assert_eq!(
+            colors("material = \"#6750a4\""),
+            Colors::Material(Material::new(Color::from_hex(0x6750a4)))
+        );
+        assert_eq!(
+            colors("open-color = \"grape\""),
+            Colors::OpenColor(OpenColor::Grape)
+        );
+        assert_eq!(
+            colors("primer = \"coral\""),
+            Colors::Primer(PrimerScale::Coral)
+        );
+        assert_eq!(colors("radix = \"iris\""), Colors::Radix(Radix::Iris));
     }
 
     #[test]
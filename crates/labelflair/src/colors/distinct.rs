@@ -0,0 +1,245 @@
+//! Color generator that gives every label a different hue
+//!
+//! This module provides a color generator for groups whose labels should be told apart at a glance,
+//! e.g. the areas of a project. Instead of shades of one hue, every label gets its own hue, and the
+//! hues are spread around the color wheel of the OKLCH color space.
+
+use getset::CopyGetters;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::color::{Color, Oklch};
+use crate::colors::{Generate, check_chroma, check_hue, check_lightness};
+
+/// The golden angle in degrees
+///
+/// Adding the golden angle to a hue over and over again never returns to a previous hue, and every
+/// new hue falls into the largest gap between the previous hues.
+const GOLDEN_ANGLE: f64 = 137.507_764_050_037_85;
+
+/// The default chroma of the generated colors
+const DEFAULT_CHROMA: f64 = 0.14;
+
+/// The default lightness of the generated colors
+const DEFAULT_LIGHTNESS: f64 = 0.7;
+
+/// Color generator that gives every label a different hue
+///
+/// The `Distinct` generator keeps the lightness and chroma of all colors the same and only changes
+/// their hue. Starting at a configurable hue, each color is rotated by the golden angle from the
+/// previous one. This spreads the hues evenly around the color wheel for any number of labels. The
+/// labels keep the order of the configuration, so adding a label to the end of a group does not
+/// change the colors of the existing labels.
+///
+/// Because the colors are computed in the OKLCH color space, they look equally light and saturated.
+#[derive(Copy, Clone, Debug, CopyGetters, Deserialize, JsonSchema)]
+#[serde(try_from = "DistinctSettings")]
+pub struct Distinct {
+    /// The hue of the first color in degrees
+    #[getset(get_copy = "pub")]
+    hue: f64,
+
+    /// The chroma of the colors
+    #[getset(get_copy = "pub")]
+    chroma: f64,
+
+    /// The lightness of the colors
+    #[getset(get_copy = "pub")]
+    lightness: f64,
+}
+
+/// The settings of a distinct generator in the configuration
+///
+/// The settings are checked when they are converted into a [`Distinct`] generator, so that values
+/// outside the OKLCH color space are rejected when the configuration is loaded.
+#[derive(Copy, Clone, Debug, Deserialize, JsonSchema)]
+struct DistinctSettings {
+    /// The hue of the first color in degrees
+    #[serde(default)]
+    hue: f64,
+
+    /// The chroma of the colors, which is `0` for gray and rarely exceeds `0.37`
+    ///
+    /// The chroma is the saturation of a color in OKLCH, so `saturation` is accepted as well.
+    #[schemars(with = "f64", range(min = 0.0), extend("default" = DEFAULT_CHROMA))]
+    #[serde(default, alias = "saturation")]
+    chroma: Option<f64>,
+
+    /// The lightness of the colors between `0` and `1`
+    #[schemars(
+        with = "f64",
+        range(min = 0.0, max = 1.0),
+        extend("default" = DEFAULT_LIGHTNESS)
+    )]
+    #[serde(default)]
+    lightness: Option<f64>,
+}
+
+impl Distinct {
+    /// Create a new color generator with distinct hues
+    ///
+    /// The values are not checked, unlike the values in the configuration.
+    pub fn new(hue: f64, chroma: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            chroma,
+            lightness,
+        }
+    }
+
+    /// Returns the components of the generator for comparisons
+    fn components(&self) -> [f64; 3] {
+        [self.hue, self.chroma, self.lightness]
+    }
+}
+
+impl Default for Distinct {
+    fn default() -> Self {
+        Self::new(0.0, DEFAULT_CHROMA, DEFAULT_LIGHTNESS)
+    }
+}
+
+impl Generate for Distinct {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        (0..count)
+            .map(|index| {
+                let hue = self.hue + index as f64 * GOLDEN_ANGLE;
+
+                Oklch::new(self.lightness, self.chroma, hue).to_color()
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<DistinctSettings> for Distinct {
+    type Error = String;
+
+    fn try_from(settings: DistinctSettings) -> Result<Self, Self::Error> {
+        let chroma = settings.chroma.unwrap_or(DEFAULT_CHROMA);
+        let lightness = settings.lightness.unwrap_or(DEFAULT_LIGHTNESS);
+
+        check_hue(settings.hue)?;
+        check_chroma(chroma)?;
+        check_lightness(lightness)?;
+
+        Ok(Self::new(settings.hue, chroma, lightness))
+    }
+}
+
+float_components!(Distinct);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use indoc::indoc;
+
+    use super::*;
+
+    /// Returns the smallest difference between two hues in degrees
+    fn hue_distance(a: f64, b: f64) -> f64 {
+        let difference = (a - b).rem_euclid(360.0);
+
+        difference.min(360.0 - difference)
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let toml = indoc! {r#"
+            hue = 30
+            chroma = 0.1
+            lightness = 0.65
+        "#};
+
+        let distinct: Distinct = toml::from_str(toml).unwrap();
+
+        assert_eq!(distinct, Distinct::new(30.0, 0.1, 0.65));
+    }
+
+    #[test]
+    fn trait_deserialize_with_saturation() {
+        let distinct: Distinct = toml::from_str("saturation = 0.1").unwrap();
+
+        assert_eq!(distinct.chroma(), 0.1);
+    }
+
+    #[test]
+    fn trait_deserialize_with_defaults() {
+        let distinct: Distinct = toml::from_str("").unwrap();
+
+        assert_eq!(distinct, Distinct::default());
+    }
+
+    #[test]
+    fn trait_deserialize_out_of_range() {
+        let error = toml::from_str::<Distinct>("lightness = 1.2").unwrap_err();
+
+        assert_eq!(
+            error.message(),
+            "lightness must be between 0 and 1, but got 1.2"
+        );
+    }
+
+    #[test]
+    fn trait_generate_starts_at_hue() {
+        let distinct = Distinct::new(140.0, 0.1, 0.7);
+
+        let colors = distinct.generate(1);
+
+        assert!(hue_distance(colors[0].to_oklch().hue(), 140.0) < 1.0);
+    }
+
+    #[test]
+    fn trait_generate_keeps_lightness() {
+        let distinct = Distinct::default();
+
+        for color in distinct.generate(8) {
+            assert!((color.to_oklch().lightness() - DEFAULT_LIGHTNESS).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn trait_generate_spreads_hues() {
+        let distinct = Distinct::default();
+
+        let hues: Vec<f64> = distinct
+            .generate(6)
+            .iter()
+            .map(|color| color.to_oklch().hue())
+            .collect();
+
+        for (index, a) in hues.iter().enumerate() {
+            for b in &hues[index + 1..] {
+                assert!(hue_distance(*a, *b) > 30.0);
+            }
+        }
+    }
+
+    #[test]
+    fn trait_generate_is_stable() {
+        let distinct = Distinct::default();
+
+        let colors = distinct.generate(5);
+
+        assert_eq!(distinct.generate(3), colors[..3]);
+        assert_eq!(colors.iter().collect::<HashSet<_>>().len(), 5);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Distinct>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Distinct>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Distinct>();
+    }
+}
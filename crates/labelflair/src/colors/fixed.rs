@@ -3,16 +3,17 @@
 //! This module provides a color generator that uses the same, pre-defined color for all labels.
 
 use getset::Getters;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
+use crate::color::Color;
 use crate::colors::Generate;
-use crate::label::Color;
 
 /// Color generator that applies a fixed color to all labels
 ///
 /// The `Fixed` color generator always returns the same color for all labels. It is initialized with
 /// a single `Color` value, which is then used for every label.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize, JsonSchema)]
 pub struct Fixed(Color);
 
 impl Fixed {
@@ -26,7 +27,7 @@ impl Fixed {
 
 impl Generate for Fixed {
     fn generate(&self, count: usize) -> Vec<Color> {
-        vec![self.0.clone(); count]
+        vec![self.0; count]
     }
 }
 
@@ -36,20 +37,20 @@ mod tests {
 
     #[test]
     fn trait_generate_for_1() {
-        let fixed = Fixed(Color::new("#ff0000"));
+        let fixed = Fixed(Color::from_hex(0xff0000));
 
         let colors = fixed.generate(1);
-        let expected = vec![Color::new("#ff0000")];
+        let expected = vec![Color::from_hex(0xff0000)];
 
         assert_eq!(colors, expected);
     }
 
     #[test]
     fn trait_generate_for_3() {
-        let fixed = Fixed(Color::new("#00ff00"));
+        let fixed = Fixed(Color::from_hex(0x00ff00));
 
         let colors = fixed.generate(3);
-        let expected = vec![Color::new("#00ff00"); 3];
+        let expected = vec![Color::from_hex(0x00ff00); 3];
 
         assert_eq!(colors, expected);
     }
@@ -0,0 +1,384 @@
+//! Color generator that interpolates between anchor colors
+//!
+//! This module provides a color generator that computes a gradient through two or more anchor
+//! colors, e.g. from a light yellow for low priorities to a dark red for critical ones. The colors
+//! are picked evenly along the gradient, so a group gets a smooth scale for any number of labels.
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+
+use crate::color::{Color, Hsl, Oklch};
+use crate::colors::Generate;
+
+/// The color space in which a gradient is interpolated
+///
+/// The color space changes the colors in the middle of a gradient. In sRGB, gradients between
+/// complementary colors pass through gray, while HSL keeps the saturation but can produce uneven
+/// lightness. OKLab is perceptually uniform and usually produces the smoothest gradients.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Interpolation {
+    /// Interpolate in the perceptually uniform OKLab color space
+    #[default]
+    Oklab,
+
+    /// Interpolate the red, green, and blue components
+    Srgb,
+
+    /// Interpolate the hue, saturation, and lightness, taking the shorter way around the hue circle
+    Hsl,
+}
+
+/// Color generator that interpolates between anchor colors
+///
+/// The `Gradient` generator places the first label on the first anchor and the last label on the
+/// last anchor, and spaces the other labels evenly in between. A single label gets the color in the
+/// middle of the gradient.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+#[serde(try_from = "GradientSettings")]
+pub struct Gradient {
+    /// The anchor colors of the gradient
+    anchors: Vec<Color>,
+
+    /// The color space in which the gradient is interpolated
+    interpolation: Interpolation,
+}
+
+/// The settings of a gradient in the configuration
+///
+/// A gradient is either a list of anchor colors, or a table with the anchors and the color space in
+/// which they are interpolated. Serde cannot report why a value did not match any variant of an
+/// untagged enum, so the settings are deserialized with plain strings first. The values are then
+/// checked when they are converted into a [`Gradient`], which results in clear error messages.
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum GradientSettings {
+    /// At least two anchor colors that are interpolated in OKLab
+    Anchors(#[schemars(with = "Vec<Color>")] Vec<String>),
+
+    /// At least two anchor colors and the color space in which they are interpolated
+    WithSpace {
+        /// The anchor colors of the gradient
+        #[schemars(with = "Vec<Color>")]
+        colors: Vec<String>,
+
+        /// The color space in which the gradient is interpolated, which defaults to `oklab`
+        #[schemars(with = "Interpolation", extend("default" = "oklab"))]
+        #[serde(default)]
+        space: Option<String>,
+    },
+}
+
+impl Gradient {
+    /// Create a new gradient through the anchor colors
+    ///
+    /// Returns `None` if fewer than two anchors are given.
+    pub fn new(anchors: Vec<Color>, interpolation: Interpolation) -> Option<Self> {
+        (anchors.len() >= 2).then_some(Self {
+            anchors,
+            interpolation,
+        })
+    }
+
+    /// Returns the anchor colors of the gradient
+    pub fn anchors(&self) -> &[Color] {
+        &self.anchors
+    }
+
+    /// Returns the color space in which the gradient is interpolated
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the color at a position between `0.0` and `1.0` along the gradient
+    fn at(&self, position: f64) -> Color {
+        let segments = self.anchors.len() - 1;
+        let scaled = position * segments as f64;
+        let index = (scaled.floor() as usize).min(segments - 1);
+
+        self.interpolation.mix(
+            self.anchors[index],
+            self.anchors[index + 1],
+            scaled - index as f64,
+        )
+    }
+}
+
+impl Interpolation {
+    /// Mix two colors, where `0.0` returns the first and `1.0` the second color
+    fn mix(&self, from: Color, to: Color, amount: f64) -> Color {
+        let lerp = |a: f64, b: f64| a + (b - a) * amount;
+
+        match self {
+            Interpolation::Oklab => {
+                let (from, to) = (from.to_oklch(), to.to_oklch());
+                let (from_a, from_b) = lab(from);
+                let (to_a, to_b) = lab(to);
+                let (a, b) = (lerp(from_a, to_a), lerp(from_b, to_b));
+
+                Oklch::new(
+                    lerp(from.lightness(), to.lightness()),
+                    a.hypot(b),
+                    b.atan2(a).to_degrees(),
+                )
+                .to_color()
+            }
+            Interpolation::Srgb => {
+                let (from, to) = (from.to_rgb(), to.to_rgb());
+                let component =
+                    |a: u8, b: u8| lerp(f64::from(a), f64::from(b)).round().clamp(0.0, 255.0) as u8;
+
+                Color::new(
+                    component(from.0, to.0),
+                    component(from.1, to.1),
+                    component(from.2, to.2),
+                )
+            }
+            Interpolation::Hsl => {
+                let (from, to) = (from.to_hsl(), to.to_hsl());
+
+                // The hue of gray is meaningless, so gray takes the hue of the other color
+                let from_hue = if from.saturation() == 0.0 {
+                    to.hue()
+                } else {
+                    from.hue()
+                };
+                let to_hue = if to.saturation() == 0.0 {
+                    from_hue
+                } else {
+                    to.hue()
+                };
+
+                // Take the shorter way around the hue circle
+                let delta = (to_hue - from_hue + 540.0).rem_euclid(360.0) - 180.0;
+
+                Hsl::new(
+                    from_hue + delta * amount,
+                    lerp(from.saturation(), to.saturation()),
+                    lerp(from.lightness(), to.lightness()),
+                )
+                .to_color()
+            }
+        }
+    }
+}
+
+/// Returns the `a` and `b` components of an OKLCH color in the OKLab color space
+fn lab(color: Oklch) -> (f64, f64) {
+    let (sin, cos) = color.hue().to_radians().sin_cos();
+
+    (color.chroma() * cos, color.chroma() * sin)
+}
+
+impl Generate for Gradient {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        (0..count)
+            .map(|index| {
+                let position = if count == 1 {
+                    0.5
+                } else {
+                    index as f64 / (count - 1) as f64
+                };
+
+                self.at(position)
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<GradientSettings> for Gradient {
+    type Error = String;
+
+    fn try_from(settings: GradientSettings) -> Result<Self, Self::Error> {
+        let (colors, space) = match settings {
+            GradientSettings::Anchors(anchors) => (anchors, None),
+            GradientSettings::WithSpace { colors, space } => (colors, space),
+        };
+
+        let anchors = colors
+            .iter()
+            .map(|color| color.parse::<Color>().map_err(|error| error.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let interpolation = match space {
+            Some(space) => Interpolation::deserialize(space.into_deserializer()).map_err(
+                |error: serde::de::value::Error| format!("invalid color space: {error}"),
+            )?,
+            None => Interpolation::default(),
+        };
+
+        let count = anchors.len();
+
+        Self::new(anchors, interpolation)
+            .ok_or_else(|| format!("a gradient needs at least two colors, but got {count}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn gradient(interpolation: Interpolation) -> Gradient {
+        Gradient::new(
+            vec![Color::from_hex(0xff0000), Color::from_hex(0x0000ff)],
+            interpolation,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_with_one_anchor() {
+        assert_eq!(
+            Gradient::new(vec![Color::from_hex(0xff0000)], Interpolation::Oklab),
+            None
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_without_space() {
+        let gradient: Gradient = toml::from_str(r##"colors = ["#fde68a", "#b91c1c"]"##).unwrap();
+
+        assert_eq!(
+            gradient.anchors(),
+            &[Color::from_hex(0xfde68a), Color::from_hex(0xb91c1c)]
+        );
+        assert_eq!(gradient.interpolation(), Interpolation::Oklab);
+    }
+
+    #[test]
+    fn trait_deserialize_list() {
+        let gradient: Gradient =
+            serde_yaml_ng::from_str("['#fde68a', '#f97316', '#b91c1c']").unwrap();
+
+        assert_eq!(gradient.anchors().len(), 3);
+        assert_eq!(gradient.interpolation(), Interpolation::Oklab);
+    }
+
+    #[test]
+    fn trait_deserialize_with_space() {
+        let toml = indoc! {r##"
+            colors = ["#fde68a", "#b91c1c"]
+            space = "hsl"
+        "##};
+
+        let gradient: Gradient = toml::from_str(toml).unwrap();
+
+        assert_eq!(gradient.interpolation(), Interpolation::Hsl);
+    }
+
+    #[test]
+    fn trait_deserialize_with_one_anchor() {
+        let error = serde_yaml_ng::from_str::<Gradient>("['#fde68a']").unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("a gradient needs at least two colors, but got 1")
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_color() {
+        let error = serde_yaml_ng::from_str::<Gradient>("['#fde68a', '#zzzzzz']").unwrap_err();
+
+        assert!(error.to_string().contains("color `#zzzzzz`"));
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_space() {
+        let toml = indoc! {r##"
+            colors = ["#fde68a", "#b91c1c"]
+            space = "lab"
+        "##};
+
+        let error = toml::from_str::<Gradient>(toml).unwrap_err();
+
+        assert_eq!(
+            error.message(),
+            "invalid color space: unknown variant `lab`, expected one of `oklab`, `srgb`, `hsl`"
+        );
+    }
+
+    #[test]
+    fn trait_generate_hits_anchors() {
+        let gradient = Gradient::new(
+            vec![
+                Color::from_hex(0xfde68a),
+                Color::from_hex(0xf97316),
+                Color::from_hex(0xb91c1c),
+            ],
+            Interpolation::Oklab,
+        )
+        .unwrap();
+
+        let colors = gradient.generate(5);
+
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[0], Color::from_hex(0xfde68a));
+        assert_eq!(colors[2], Color::from_hex(0xf97316));
+        assert_eq!(colors[4], Color::from_hex(0xb91c1c));
+    }
+
+    #[test]
+    fn trait_generate_for_1() {
+        let colors = gradient(Interpolation::Srgb).generate(1);
+
+        assert_eq!(colors, vec![Color::new(128, 0, 128)]);
+    }
+
+    #[test]
+    fn trait_generate_in_srgb() {
+        let colors = gradient(Interpolation::Srgb).generate(3);
+
+        assert_eq!(
+            colors,
+            vec![
+                Color::from_hex(0xff0000),
+                Color::new(128, 0, 128),
+                Color::from_hex(0x0000ff),
+            ]
+        );
+    }
+
+    #[test]
+    fn trait_generate_in_hsl() {
+        let colors = gradient(Interpolation::Hsl).generate(3);
+
+        // The shorter way from red to blue goes through magenta
+        assert_eq!(colors[1], Color::from_hex(0xff00ff));
+    }
+
+    #[test]
+    fn trait_generate_in_oklab() {
+        let colors = gradient(Interpolation::Oklab).generate(3);
+        let middle = colors[1].to_oklch().lightness();
+
+        let red = Color::from_hex(0xff0000).to_oklch().lightness();
+        let blue = Color::from_hex(0x0000ff).to_oklch().lightness();
+
+        assert!((middle - (red + blue) / 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Gradient>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Gradient>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Gradient>();
+    }
+}
@@ -0,0 +1,101 @@
+//! Color generator based on the tonal palettes of Material Design 3
+//!
+//! This module provides a color generator that computes a tonal palette from a seed color, the way
+//! Material Design 3 derives the colors of a theme. A tonal palette keeps the hue and chroma of the
+//! seed color and changes only its tone, which is the perceived lightness.
+//!
+//! See https://m3.material.io/styles/color/system/how-the-system-works for more information on
+//! tonal palettes.
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::color::{Color, Hct};
+use crate::colors::{Generate, spread};
+
+/// The tones of the palette that are used for labels, from light to dark
+///
+/// Material Design also defines the tones `0`, `95`, `99`, and `100`, which are too close to black
+/// and white to be used for labels.
+const TONES: [f64; 9] = [90.0, 80.0, 70.0, 60.0, 50.0, 40.0, 30.0, 20.0, 10.0];
+
+/// Color generator based on the tonal palettes of Material Design 3
+///
+/// The `Material` generator converts its seed color into the HCT color space of Material Design, and
+/// computes the tones `90` to `10` with the hue and chroma of the seed. Like the Tailwind generator,
+/// it spreads the labels of a group evenly across the tones, and repeats the tones if a group has
+/// more labels than the palette has tones.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+pub struct Material(Color);
+
+impl Material {
+    /// Create a new color generator from a seed color
+    pub fn new(seed: Color) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the seed color of the tonal palette
+    pub fn seed(&self) -> Color {
+        self.0
+    }
+}
+
+impl Generate for Material {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        let seed = self.0.to_hct();
+        let shades = TONES.map(|tone| Hct::new(seed.hue(), seed.chroma(), tone).to_color());
+
+        spread(count, &shades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_deserialize() {
+        let material: Material = serde_yaml_ng::from_str("'#6750a4'").unwrap();
+
+        assert_eq!(material.seed(), Color::from_hex(0x6750a4));
+    }
+
+    #[test]
+    fn trait_generate_tonal_palette() {
+        let material = Material::new(Color::from_hex(0x6750a4));
+
+        let colors = material.generate(9);
+
+        assert_eq!(colors[0], Color::from_hex(0xeaddff));
+        assert_eq!(colors[1], Color::from_hex(0xd0bcff));
+        assert_eq!(colors[8], Color::from_hex(0x21005d));
+    }
+
+    #[test]
+    fn trait_generate_keeps_hue() {
+        let material = Material::new(Color::from_hex(0x386a20));
+        let hue = Color::from_hex(0x386a20).to_hct().hue();
+
+        for color in material.generate(5) {
+            assert!((color.to_hct().hue() - hue).abs() < 3.0);
+        }
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Material>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Material>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Material>();
+    }
+}
@@ -0,0 +1,226 @@
+//! Color generator based on the OKLCH color space
+//!
+//! This module provides a color generator that computes shades of a single hue in the OKLCH color
+//! space. Because OKLCH is perceptually uniform, shades that are evenly spaced in lightness also
+//! look evenly spaced, and the generator can produce any number of distinct colors.
+//!
+//! See https://oklch.com for an interactive explanation of the color space.
+
+use getset::CopyGetters;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::color::{Color, Oklch};
+use crate::colors::{Generate, check_chroma, check_hue, check_lightness};
+
+/// The default chroma of the generated colors
+const DEFAULT_CHROMA: f64 = 0.12;
+
+/// The default range of the lightness of the generated colors
+const DEFAULT_LIGHTNESS: [f64; 2] = [0.45, 0.85];
+
+/// Color generator based on the OKLCH color space
+///
+/// The `OklchScale` generator produces shades of a single hue with the same chroma. The lightness
+/// of the shades is spaced evenly across a range, starting at the first and ending at the second
+/// value of the range. A single color is placed in the middle of the range.
+///
+/// Colors that do not fit into the sRGB color space lose some of their chroma, but keep their
+/// lightness and hue.
+#[derive(Copy, Clone, Debug, CopyGetters, Deserialize, JsonSchema)]
+#[serde(try_from = "OklchSettings")]
+pub struct OklchScale {
+    /// The hue of the colors in degrees
+    #[getset(get_copy = "pub")]
+    hue: f64,
+
+    /// The chroma of the colors
+    #[getset(get_copy = "pub")]
+    chroma: f64,
+
+    /// The range of the lightness of the colors
+    #[getset(get_copy = "pub")]
+    lightness: [f64; 2],
+}
+
+/// The settings of an OKLCH scale in the configuration
+///
+/// The settings are checked when they are converted into an [`OklchScale`], so that values outside
+/// the OKLCH color space are rejected when the configuration is loaded.
+#[derive(Copy, Clone, Debug, Deserialize, JsonSchema)]
+struct OklchSettings {
+    /// The hue of the colors in degrees, e.g. `250` for blue
+    hue: f64,
+
+    /// The chroma of the colors, which is `0` for gray and rarely exceeds `0.37`
+    #[schemars(with = "f64", range(min = 0.0), extend("default" = DEFAULT_CHROMA))]
+    #[serde(default)]
+    chroma: Option<f64>,
+
+    /// The range of the lightness of the colors, each between `0` and `1`
+    #[schemars(with = "[f64; 2]", extend("default" = DEFAULT_LIGHTNESS))]
+    #[serde(default)]
+    lightness: Option<[f64; 2]>,
+}
+
+impl OklchScale {
+    /// Create a new OKLCH color generator
+    ///
+    /// The lightness of the generated colors goes from the first to the second value of the range.
+    /// The values are not checked, unlike the values in the configuration.
+    pub fn new(hue: f64, chroma: f64, lightness: [f64; 2]) -> Self {
+        Self {
+            hue,
+            chroma,
+            lightness,
+        }
+    }
+
+    /// Returns the components of the generator for comparisons
+    fn components(&self) -> [f64; 4] {
+        [self.hue, self.chroma, self.lightness[0], self.lightness[1]]
+    }
+}
+
+impl Generate for OklchScale {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        let [start, end] = self.lightness;
+
+        (0..count)
+            .map(|index| {
+                let position = if count == 1 {
+                    0.5
+                } else {
+                    index as f64 / (count - 1) as f64
+                };
+
+                Oklch::new(start + (end - start) * position, self.chroma, self.hue).to_color()
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<OklchSettings> for OklchScale {
+    type Error = String;
+
+    fn try_from(settings: OklchSettings) -> Result<Self, Self::Error> {
+        let chroma = settings.chroma.unwrap_or(DEFAULT_CHROMA);
+        let lightness = settings.lightness.unwrap_or(DEFAULT_LIGHTNESS);
+
+        check_hue(settings.hue)?;
+        check_chroma(chroma)?;
+
+        for value in lightness {
+            check_lightness(value)?;
+        }
+
+        Ok(Self::new(settings.hue, chroma, lightness))
+    }
+}
+
+float_components!(OklchScale);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn trait_deserialize() {
+        let toml = indoc! {r#"
+            hue = 250
+            chroma = 0.1
+            lightness = [0.4, 0.9]
+        "#};
+
+        let oklch: OklchScale = toml::from_str(toml).unwrap();
+
+        assert_eq!(oklch, OklchScale::new(250.0, 0.1, [0.4, 0.9]));
+    }
+
+    #[test]
+    fn trait_deserialize_with_defaults() {
+        let oklch: OklchScale = toml::from_str("hue = 250").unwrap();
+
+        assert_eq!(
+            oklch,
+            OklchScale::new(250.0, DEFAULT_CHROMA, DEFAULT_LIGHTNESS)
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_out_of_range() {
+        let chroma = toml::from_str::<OklchScale>("hue = 250\nchroma = -0.1").unwrap_err();
+        let lightness =
+            toml::from_str::<OklchScale>("hue = 250\nlightness = [0.4, 1.5]").unwrap_err();
+
+        assert_eq!(chroma.message(), "chroma must be at least 0, but got -0.1");
+        assert_eq!(
+            lightness.message(),
+            "lightness must be between 0 and 1, but got 1.5"
+        );
+    }
+
+    #[test]
+    fn trait_generate_for_1() {
+        let oklch = OklchScale::new(250.0, 0.12, [0.45, 0.85]);
+
+        let colors = oklch.generate(1);
+
+        assert_eq!(colors.len(), 1);
+        assert!((colors[0].to_oklch().lightness() - 0.65).abs() < 0.01);
+    }
+
+    #[test]
+    fn trait_generate_spaces_lightness_evenly() {
+        let oklch = OklchScale::new(250.0, 0.12, [0.45, 0.85]);
+
+        let lightness: Vec<f64> = oklch
+            .generate(5)
+            .iter()
+            .map(|color| color.to_oklch().lightness())
+            .collect();
+
+        for (actual, expected) in lightness.iter().zip([0.45, 0.55, 0.65, 0.75, 0.85]) {
+            assert!((actual - expected).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn trait_generate_without_repetition() {
+        let oklch = OklchScale::new(250.0, 0.12, [0.45, 0.85]);
+
+        let colors = oklch.generate(15);
+        let unique: HashSet<&Color> = colors.iter().collect();
+
+        assert_eq!(unique.len(), 15);
+    }
+
+    #[test]
+    fn trait_generate_for_0() {
+        let oklch = OklchScale::new(250.0, 0.12, [0.45, 0.85]);
+
+        assert!(oklch.generate(0).is_empty());
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<OklchScale>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<OklchScale>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<OklchScale>();
+    }
+}
@@ -0,0 +1,211 @@
+//! Color generator based on a palette in the configuration
+//!
+//! This module provides a color generator that uses a palette that has been defined by the user,
+//! e.g. the brand colors of a design system. Palettes are defined once at the top of the
+//! configuration and can be referenced by name from many groups.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::Deserialize;
+use typed_fields::name;
+
+use crate::Error;
+use crate::color::Color;
+use crate::colors::{Generate, spread};
+
+name!(
+    /// The name of a palette in the configuration
+    PaletteName
+);
+
+/// A palette of colors in the configuration
+///
+/// A palette is an ordered list of shades, usually from light to dark. Like with the Tailwind
+/// palettes, the shades that are used for a group are spaced evenly across the palette, and the
+/// palette repeats if a group has more labels than the palette has shades.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize, JsonSchema)]
+#[serde(try_from = "PaletteSettings")]
+pub struct Palette {
+    /// The shades of the palette in order
+    #[getset(get = "pub")]
+    shades: Vec<Color>,
+}
+
+/// The settings of a palette in the configuration
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+struct PaletteSettings {
+    /// The shades of the palette in order
+    #[schemars(length(min = 1))]
+    shades: Vec<Color>,
+}
+
+/// Color generator that uses a palette in the configuration
+///
+/// The `PaletteRef` is deserialized from the name of a palette. The palette is looked up by its
+/// name when the labels of a group are generated, since the palettes are defined outside of the
+/// group.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize)]
+#[serde(transparent)]
+pub struct PaletteRef {
+    /// The name of the palette
+    #[getset(get = "pub")]
+    name: PaletteName,
+}
+
+impl Palette {
+    /// Create a new palette from its shades
+    ///
+    /// Returns `None` if no shades are given.
+    pub fn new(shades: Vec<Color>) -> Option<Self> {
+        (!shades.is_empty()).then_some(Self { shades })
+    }
+}
+
+impl PaletteRef {
+    /// Create a new reference to a palette
+    pub fn new(name: impl Into<PaletteName>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Look up the palette with the name in the palettes of the configuration
+    ///
+    /// Returns [`Error::UnknownPalette`] if no palette has been defined under the name.
+    pub fn resolve<'a>(
+        &self,
+        palettes: &'a BTreeMap<PaletteName, Palette>,
+    ) -> Result<&'a Palette, Error> {
+        palettes
+            .get(&self.name)
+            .ok_or_else(|| Error::UnknownPalette {
+                name: self.name.to_string(),
+            })
+    }
+}
+
+impl Generate for Palette {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        spread(count, &self.shades)
+    }
+}
+
+impl TryFrom<PaletteSettings> for Palette {
+    type Error = String;
+
+    fn try_from(settings: PaletteSettings) -> Result<Self, Self::Error> {
+        Self::new(settings.shades).ok_or_else(|| "a palette needs at least one shade".into())
+    }
+}
+
+impl JsonSchema for PaletteRef {
+    fn schema_name() -> Cow<'static, str> {
+        "PaletteRef".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The name of a palette that is defined in the `palette` table",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn palette(count: u32) -> Palette {
+        Palette::new((0..count).map(Color::from_hex).collect()).unwrap()
+    }
+
+    #[test]
+    fn trait_deserialize_palette() {
+        let palette: Palette = toml::from_str(r##"shades = ["#eff6ff", "#1e3a8a"]"##).unwrap();
+
+        assert_eq!(
+            palette.shades(),
+            &vec![Color::from_hex(0xeff6ff), Color::from_hex(0x1e3a8a)]
+        );
+    }
+
+    #[test]
+    fn new_without_shades() {
+        assert!(Palette::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn resolve() {
+        let palettes = BTreeMap::from([(PaletteName::new("brand"), palette(9))]);
+
+        let resolved = PaletteRef::new("brand").resolve(&palettes).unwrap();
+
+        assert_eq!(resolved, &palette(9));
+    }
+
+    #[test]
+    fn resolve_unknown_palette() {
+        let error = PaletteRef::new("brand")
+            .resolve(&BTreeMap::new())
+            .unwrap_err();
+
+        assert!(matches!(error, Error::UnknownPalette { name } if name == "brand"));
+    }
+
+    #[test]
+    fn trait_deserialize_reference() {
+        let reference: PaletteRef = serde_yaml_ng::from_str("brand").unwrap();
+
+        assert_eq!(reference, PaletteRef::new("brand"));
+    }
+
+    #[test]
+    fn trait_deserialize_palette_without_shades() {
+        let error = toml::from_str::<Palette>("shades = []").unwrap_err();
+
+        assert!(
+            error
+                .message()
+                .contains("a palette needs at least one shade")
+        );
+    }
+
+    #[test]
+    fn trait_generate_spaces_shades() {
+        let colors = palette(12).generate(3);
+
+        assert_eq!(
+            colors,
+            vec![Color::from_hex(2), Color::from_hex(6), Color::from_hex(10)]
+        );
+    }
+
+    #[test]
+    fn trait_generate_repeats_shades() {
+        let colors = palette(2).generate(3);
+
+        assert_eq!(
+            colors,
+            vec![Color::from_hex(0), Color::from_hex(1), Color::from_hex(0)]
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PaletteRef>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PaletteRef>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<PaletteRef>();
+    }
+}
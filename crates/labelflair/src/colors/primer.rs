@@ -0,0 +1,139 @@
+//! Color generator based on the base color scales of the Primer design system
+//!
+//! This module provides a color generator that uses the base color scales of Primer, the design
+//! system of GitHub. These are the `scale` colors that Primer derives its functional colors from,
+//! not the colors that GitHub uses for its own labels. Primer numbers the shades of each color from
+//! `0` to `9`. Labels use the shades `1` to `8`, since the lightest and the darkest shade are hard
+//! to tell apart from white and black.
+//!
+//! See https://primer.style/foundations/color for more information on the colors of Primer.
+
+use std::ops::RangeInclusive;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::color::Color;
+use crate::colors::{Generate, spread};
+
+/// The number of shades of each color
+const SHADES: usize = 10;
+
+/// The positions of the shades that are used for labels, which are the shades `1` to `8`
+const DEFAULT_SHADES: RangeInclusive<usize> = 1..=8;
+
+/// Color generator based on the base color scales of the Primer design system
+///
+/// The `PrimerScale` enum represents the base color scales of Primer, which match the colors of
+/// the rest of GitHub. Like the Tailwind generator, it spreads the labels of a group evenly across
+/// the shades of the color, and repeats the shades if a group has more labels than the color has
+/// shades.
+///
+/// See https://primer.style/foundations/color for more information on the colors of Primer.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[allow(missing_docs)] // Enum variants are self-explanatory
+pub enum PrimerScale {
+    Gray,
+    Blue,
+    Green,
+    Yellow,
+    Orange,
+    Red,
+    Purple,
+    Pink,
+    Coral,
+}
+
+impl PrimerScale {
+    /// Get the shades of the color from light to dark
+    ///
+    /// The shades are taken from the light theme of Primer and numbered from `0` to `9`.
+    fn colors(&self) -> [Color; SHADES] {
+        match self {
+            PrimerScale::Gray => [
+                0xf6f8fa, 0xeaeef2, 0xd0d7de, 0xafb8c1, 0x8c959f, 0x6e7781, 0x57606a, 0x424a53,
+                0x32383f, 0x24292f,
+            ],
+            PrimerScale::Blue => [
+                0xddf4ff, 0xb6e3ff, 0x80ccff, 0x54aeff, 0x218bff, 0x0969da, 0x0550ae, 0x033d8b,
+                0x0a3069, 0x002155,
+            ],
+            PrimerScale::Green => [
+                0xdafbe1, 0xaceebb, 0x6fdd8b, 0x4ac26b, 0x2da44e, 0x1a7f37, 0x116329, 0x044f1e,
+                0x003d16, 0x002d11,
+            ],
+            PrimerScale::Yellow => [
+                0xfff8c5, 0xfae17d, 0xeac54f, 0xd4a72c, 0xbf8700, 0x9a6700, 0x7d4e00, 0x633c01,
+                0x4d2d00, 0x3b2300,
+            ],
+            PrimerScale::Orange => [
+                0xfff1e5, 0xffd8b5, 0xffb77c, 0xfb8f44, 0xe16f24, 0xbc4c00, 0x953800, 0x762c00,
+                0x5c2200, 0x471700,
+            ],
+            PrimerScale::Red => [
+                0xffebe9, 0xffcecb, 0xffaba8, 0xff8182, 0xfa4549, 0xcf222e, 0xa40e26, 0x82071e,
+                0x660018, 0x4c0014,
+            ],
+            PrimerScale::Purple => [
+                0xfbefff, 0xecd8ff, 0xd8b9ff, 0xc297ff, 0xa475f9, 0x8250df, 0x6639ba, 0x512a97,
+                0x3e1f79, 0x2e1461,
+            ],
+            PrimerScale::Pink => [
+                0xffeff7, 0xffd3eb, 0xffadda, 0xff80c8, 0xe85aad, 0xbf3989, 0x99286e, 0x772057,
+                0x611347, 0x4d0336,
+            ],
+            PrimerScale::Coral => [
+                0xfff0eb, 0xffd6cc, 0xffb4a1, 0xfd8c73, 0xec6547, 0xc4432b, 0x9e2f1c, 0x801f0f,
+                0x691105, 0x510901,
+            ],
+        }
+        .map(Color::from_hex)
+    }
+}
+
+impl Generate for PrimerScale {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        spread(count, &self.colors()[DEFAULT_SHADES])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_deserialize() {
+        let primer: PrimerScale = serde_yaml_ng::from_str("coral").unwrap();
+
+        assert_eq!(primer, PrimerScale::Coral);
+    }
+
+    #[test]
+    fn trait_generate_green() {
+        let colors = PrimerScale::Green.generate(2);
+
+        assert_eq!(
+            colors,
+            vec![Color::from_hex(0x4ac26b), Color::from_hex(0x044f1e)]
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<PrimerScale>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<PrimerScale>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<PrimerScale>();
+    }
+}
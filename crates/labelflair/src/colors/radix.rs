@@ -0,0 +1,260 @@
+//! Color generator based on Radix Colors
+//!
+//! This module provides a color generator that uses the light color scales of Radix Colors. Each
+//! scale has 12 steps, which Radix designed for specific purposes: the first steps are backgrounds,
+//! the middle steps borders and solid colors, and the last steps text. Labels use the steps 3 to 11,
+//! since the first two steps are almost white.
+//!
+//! See https://www.radix-ui.com/colors for more information on Radix Colors.
+
+use std::ops::RangeInclusive;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::color::Color;
+use crate::colors::{Generate, spread};
+
+/// The number of shades of each color
+const STEPS: usize = 12;
+
+/// The positions of the shades that are used for labels, which are the steps 3 to 11
+const DEFAULT_SHADES: RangeInclusive<usize> = 2..=10;
+
+/// Color generator based on Radix Colors
+///
+/// The `Radix` enum represents the color scales of Radix Colors, including its grays. Like the
+/// Tailwind generator, it spreads the labels of a group evenly across the steps of the scale, and
+/// repeats the steps if a group has more labels than the scale has steps.
+///
+/// See https://www.radix-ui.com/colors for more information on Radix Colors.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[allow(missing_docs)] // Enum variants are self-explanatory
+pub enum Radix {
+    Gray,
+    Mauve,
+    Slate,
+    Sage,
+    Olive,
+    Sand,
+    Tomato,
+    Red,
+    Ruby,
+    Crimson,
+    Pink,
+    Plum,
+    Purple,
+    Violet,
+    Iris,
+    Indigo,
+    Blue,
+    Cyan,
+    Teal,
+    Jade,
+    Green,
+    Grass,
+    Bronze,
+    Gold,
+    Brown,
+    Orange,
+    Amber,
+    Yellow,
+    Lime,
+    Mint,
+    Sky,
+}
+
+impl Radix {
+    /// Get the shades of the color from light to dark
+    ///
+    /// The steps are taken from the light theme of Radix Colors.
+    fn colors(&self) -> [Color; STEPS] {
+        match self {
+            Radix::Gray => [
+                0xfcfcfc, 0xf9f9f9, 0xf0f0f0, 0xe8e8e8, 0xe0e0e0, 0xd9d9d9, 0xcecece, 0xbbbbbb,
+                0x8d8d8d, 0x838383, 0x646464, 0x202020,
+            ],
+            Radix::Mauve => [
+                0xfdfcfd, 0xfaf9fb, 0xf2eff3, 0xeae7ec, 0xe3dfe6, 0xdbd8e0, 0xd0cdd7, 0xbcbac7,
+                0x8e8c99, 0x84828e, 0x65636d, 0x211f26,
+            ],
+            Radix::Slate => [
+                0xfcfcfd, 0xf9f9fb, 0xf0f0f3, 0xe8e8ec, 0xe0e1e6, 0xd9d9e0, 0xcdced6, 0xb9bbc6,
+                0x8b8d98, 0x80838d, 0x60646c, 0x1c2024,
+            ],
+            Radix::Sage => [
+                0xfbfdfc, 0xf7f9f8, 0xeef1f0, 0xe6e9e8, 0xdfe2e0, 0xd7dad9, 0xcbcfcd, 0xb8bcba,
+                0x868e8b, 0x7c8481, 0x5f6563, 0x1a211e,
+            ],
+            Radix::Olive => [
+                0xfcfdfc, 0xf8faf8, 0xeff1ef, 0xe7e9e7, 0xdfe2df, 0xd7dad7, 0xcccfcc, 0xb9bcb8,
+                0x898e87, 0x7f847d, 0x60655f, 0x1d211c,
+            ],
+            Radix::Sand => [
+                0xfdfdfc, 0xf9f9f8, 0xf1f0ef, 0xe9e8e6, 0xe2e1de, 0xdad9d6, 0xcfceca, 0xbcbbb5,
+                0x8d8d86, 0x82827c, 0x63635e, 0x21201c,
+            ],
+            Radix::Tomato => [
+                0xfffcfc, 0xfff8f7, 0xfeebe7, 0xffdcd3, 0xffcdc2, 0xfdbdaf, 0xf5a898, 0xec8e7b,
+                0xe54d2e, 0xdd4425, 0xd13415, 0x5c271f,
+            ],
+            Radix::Red => [
+                0xfffcfc, 0xfff7f7, 0xfeebec, 0xffdbdc, 0xffcdce, 0xfdbdbe, 0xf4a9aa, 0xeb8e90,
+                0xe5484d, 0xdc3e42, 0xce2c31, 0x641723,
+            ],
+            Radix::Ruby => [
+                0xfffcfd, 0xfff7f8, 0xfeeaed, 0xffdce1, 0xffced6, 0xf8bfc8, 0xefacb8, 0xe592a3,
+                0xe54666, 0xdc3b5d, 0xca244d, 0x64172b,
+            ],
+            Radix::Crimson => [
+                0xfffcfd, 0xfef7f9, 0xffe9f0, 0xfedce7, 0xfacedd, 0xf3bed1, 0xeaacc3, 0xe093b2,
+                0xe93d82, 0xdf3478, 0xcb1d63, 0x621639,
+            ],
+            Radix::Pink => [
+                0xfffcfe, 0xfef7fb, 0xfee9f5, 0xfbdcef, 0xf6cee7, 0xefbfdd, 0xe7acd0, 0xdd93c2,
+                0xd6409f, 0xcf3897, 0xc2298a, 0x651249,
+            ],
+            Radix::Plum => [
+                0xfefcff, 0xfdf7fd, 0xfbebfb, 0xf7def8, 0xf2d1f3, 0xe9c2ec, 0xdeade3, 0xcf91d8,
+                0xab4aba, 0xa144af, 0x953ea3, 0x53195d,
+            ],
+            Radix::Purple => [
+                0xfefcfe, 0xfbf7fe, 0xf7edfe, 0xf2e2fc, 0xead5f9, 0xe0c4f4, 0xd1afec, 0xbe93e4,
+                0x8e4ec6, 0x8347b9, 0x8145b5, 0x402060,
+            ],
+            Radix::Violet => [
+                0xfdfcfe, 0xfaf8ff, 0xf4f0fe, 0xebe4ff, 0xe1d9ff, 0xd4cafe, 0xc2b5f5, 0xaa99ec,
+                0x6e56cf, 0x654dc4, 0x6550b9, 0x2f265f,
+            ],
+            Radix::Iris => [
+                0xfdfdff, 0xf8f8ff, 0xf0f1fe, 0xe6e7ff, 0xdadcff, 0xcbcdff, 0xb8baf8, 0x9b9ef0,
+                0x5b5bd6, 0x5151cd, 0x5753c6, 0x272962,
+            ],
+            Radix::Indigo => [
+                0xfdfdfe, 0xf7f9ff, 0xedf2fe, 0xe1e9ff, 0xd2deff, 0xc1d0ff, 0xabbdf9, 0x8da4ef,
+                0x3e63dd, 0x3358d4, 0x3a5bc7, 0x1f2d5c,
+            ],
+            Radix::Blue => [
+                0xfbfdff, 0xf4faff, 0xe6f4fe, 0xd5efff, 0xc2e5ff, 0xacd8fc, 0x8ec8f6, 0x5eb1ef,
+                0x0090ff, 0x0588f0, 0x0d74ce, 0x113264,
+            ],
+            Radix::Cyan => [
+                0xfafdfe, 0xf2fafb, 0xdef7f9, 0xcaf1f6, 0xb5e9f0, 0x9ddde7, 0x7dcedc, 0x3db9cf,
+                0x00a2c7, 0x0797b9, 0x107d98, 0x0d3c48,
+            ],
+            Radix::Teal => [
+                0xfafefd, 0xf3fbf9, 0xe0f8f3, 0xccf3ea, 0xb8eae0, 0xa1ded2, 0x83cdc1, 0x53b9ab,
+                0x12a594, 0x0d9b8a, 0x008573, 0x0d3d38,
+            ],
+            Radix::Jade => [
+                0xfbfefd, 0xf4fbf7, 0xe6f7ed, 0xd6f1e3, 0xc3e9d7, 0xacdec8, 0x8bceb6, 0x56ba9f,
+                0x29a383, 0x26997b, 0x208368, 0x1d3b31,
+            ],
+            Radix::Green => [
+                0xfbfefc, 0xf4fbf6, 0xe6f6eb, 0xd6f1df, 0xc4e8d1, 0xadddc0, 0x8eceaa, 0x5bb98b,
+                0x30a46c, 0x2b9a66, 0x218358, 0x193b2d,
+            ],
+            Radix::Grass => [
+                0xfbfefb, 0xf5fbf5, 0xe9f6e9, 0xdaf1db, 0xc9e8ca, 0xb2ddb5, 0x94ce9a, 0x65ba74,
+                0x46a758, 0x3e9b4f, 0x2a7e3b, 0x203c25,
+            ],
+            Radix::Bronze => [
+                0xfdfcfc, 0xfdf7f5, 0xf6edea, 0xefe4df, 0xe7d9d3, 0xdfcdc5, 0xd3bcb3, 0xc2a499,
+                0xa18072, 0x957468, 0x7d5e54, 0x43302b,
+            ],
+            Radix::Gold => [
+                0xfdfdfc, 0xfaf9f2, 0xf2f0e7, 0xeae6db, 0xe1dccf, 0xd8d0bf, 0xcbc0aa, 0xb9a88d,
+                0x978365, 0x8c7a5e, 0x71624b, 0x3b352b,
+            ],
+            Radix::Brown => [
+                0xfefdfc, 0xfcf9f6, 0xf6eee7, 0xf0e4d9, 0xebdaca, 0xe4cdb7, 0xdcbc9f, 0xcea37e,
+                0xad7f58, 0xa07553, 0x815e46, 0x3e332e,
+            ],
+            Radix::Orange => [
+                0xfefcfb, 0xfff7ed, 0xffefd6, 0xffdfb5, 0xffd19a, 0xffc182, 0xf5ae73, 0xec9455,
+                0xf76b15, 0xef5f00, 0xcc4e00, 0x582d1d,
+            ],
+            Radix::Amber => [
+                0xfefdfb, 0xfefbe9, 0xfff7c2, 0xffee9c, 0xfbe577, 0xf3d673, 0xe9c162, 0xe2a336,
+                0xffc53d, 0xffba18, 0xab6400, 0x4f3422,
+            ],
+            Radix::Yellow => [
+                0xfdfdf9, 0xfefce9, 0xfffab8, 0xfff394, 0xffe770, 0xf3d768, 0xe4c767, 0xd5ae39,
+                0xffe629, 0xffdc00, 0x9e6c00, 0x473b1f,
+            ],
+            Radix::Lime => [
+                0xfcfdfa, 0xf8faf3, 0xeef6d6, 0xe2f0bd, 0xd3e7a6, 0xc2da91, 0xabc978, 0x8db654,
+                0xbdee63, 0xb0e64c, 0x5c7c2f, 0x37401c,
+            ],
+            Radix::Mint => [
+                0xf9fefd, 0xf2fbf9, 0xddf9f2, 0xc8f4e9, 0xb3ecde, 0x9ce0d0, 0x7ecfbd, 0x4cbba5,
+                0x86ead4, 0x7de0cb, 0x027864, 0x16433c,
+            ],
+            Radix::Sky => [
+                0xf9feff, 0xf1fafd, 0xe1f6fd, 0xd1f0fa, 0xbee7f5, 0xa9daed, 0x8dcae3, 0x60b3d7,
+                0x7ce2fe, 0x74daf8, 0x00749e, 0x1d3e56,
+            ],
+        }
+        .map(Color::from_hex)
+    }
+}
+
+impl Generate for Radix {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        spread(count, &self.colors()[DEFAULT_SHADES])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trait_deserialize() {
+        let radix: Radix = serde_yaml_ng::from_str("iris").unwrap();
+
+        assert_eq!(radix, Radix::Iris);
+    }
+
+    #[test]
+    fn trait_generate_blue() {
+        let colors = Radix::Blue.generate(3);
+
+        assert_eq!(
+            colors,
+            vec![
+                Color::from_hex(0xd5efff),
+                Color::from_hex(0x8ec8f6),
+                Color::from_hex(0x0588f0),
+            ]
+        );
+    }
+
+    #[test]
+    fn trait_generate_repeats_steps() {
+        let colors = Radix::Red.generate(10);
+
+        assert_eq!(colors[0], Color::from_hex(0xfeebec));
+        assert_eq!(colors[8], Color::from_hex(0xce2c31));
+        assert_eq!(colors[9], colors[0]);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Radix>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Radix>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Radix>();
+    }
+}
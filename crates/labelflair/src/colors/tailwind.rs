@@ -1,27 +1,44 @@
 //! Color generator based on the Tailwind CSS color palettes
 //!
 //! This module provides a color generator that uses the Tailwind CSS color palettes to generate
-//! colors. It uses a subset of each color palette to avoid very bright and dark variants.
+//! colors. By default, it skips the lightest and darkest shades of each color palette, because they
+//! are hard to tell apart from white and black. Both the palettes of Tailwind CSS v3 and v4 are
+//! available.
 //!
 //! See https://tailwindcss.com/docs/colors for more information on the Tailwind CSS color palettes.
 
-use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
 
-use crate::colors::Generate;
-use crate::label::Color;
+use getset::{CopyGetters, Getters};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer};
+use typed_builder::TypedBuilder;
+
+use crate::color::{self, Color};
+use crate::colors::{Generate, centered_indices};
 
 /// The number of shades available for each Tailwind color
-const SHADES_COUNT: usize = 9;
+const SHADES_COUNT: usize = 11;
+
+/// The shades of the Tailwind colors that are available, from light to dark
+const SHADES: [u16; SHADES_COUNT] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// The positions of the shades that are used by default, which are the shades `100` to `900`
+const DEFAULT_SHADES: RangeInclusive<usize> = 1..=9;
+
+/// The versions of Tailwind CSS whose palettes are available
+const VERSIONS: [u8; 2] = [3, 4];
 
 /// Color generator based on the Tailwind CSS color palettes
 ///
 /// The `Tailwind` enum represents the different colors available in the Tailwind CSS color palette.
-/// It implements the `Generate` trait to produce a list of colors based on the selected color and
-/// the count of colors to generate. The colors are generated in an alternating pattern to provide
-/// a balanced distribution of shades.
+/// A `TailwindScale` picks evenly spaced shades of the selected color for the labels of a group, so
+/// that the colors are balanced across the palette.
 ///
 /// See https://tailwindcss.com/docs/colors for more information on the Tailwind CSS color palettes.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 #[allow(missing_docs)] // Enum variants are self-explanatory
 pub enum Tailwind {
@@ -45,146 +62,653 @@ pub enum Tailwind {
     Slate,
     Gray,
     Zinc,
+    Neutral,
+    #[deprecated(note = "use `Tailwind::Neutral` instead")]
     Natural,
     Stone,
 }
 
-impl Tailwind {
-    /// Generate a list of evenly spaced indices for the Tailwind color shades
+/// The version of Tailwind CSS whose palettes are used
+///
+/// Tailwind CSS v4 redefined its palettes in the OKLCH color space, which changed most shades
+/// slightly. Colors outside of the sRGB gamut lose some of their chroma, since labels on GitHub can
+/// only use sRGB colors.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum TailwindVersion {
+    /// The palettes of Tailwind CSS v3, which are defined as hex colors
+    #[default]
+    V3,
+
+    /// The palettes of Tailwind CSS v4, which are defined in OKLCH
+    V4,
+}
+
+/// A shade of a Tailwind color, e.g. `500`
+///
+/// Tailwind numbers the shades of its colors from light to dark, starting at `50` and ending at
+/// `950`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Shade(u16);
+
+/// The order in which the shades are assigned to the labels of a group
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Order {
+    /// Assign the lightest shade to the first label and the darkest shade to the last label
+    #[default]
+    LightToDark,
+
+    /// Assign the darkest shade to the first label and the lightest shade to the last label
+    DarkToLight,
+
+    /// Alternate between light and dark shades, starting with the lightest shade
+    Alternating,
+}
+
+/// Color generator based on a Tailwind color with a selection of its shades
+///
+/// The `TailwindScale` picks the shades for a group from either an explicit list of shades or a
+/// range of shades, and assigns them to the labels in the given [`Order`]. Without a selection, the
+/// shades `100` to `900` of the color are used.
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, CopyGetters, Getters, TypedBuilder,
+)]
+pub struct TailwindScale {
+    /// The Tailwind color
+    #[getset(get_copy = "pub")]
+    color: Tailwind,
+
+    /// An explicit list of shades to pick from
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    shades: Option<Vec<Shade>>,
+
+    /// The lightest and the darkest shade to pick from
+    #[builder(default, setter(into))]
+    #[getset(get_copy = "pub")]
+    range: Option<[Shade; 2]>,
+
+    /// The order in which the shades are assigned to the labels
     ///
-    /// If the requested count is less than the number of shades, this method spaces the picked
-    /// indices evenly across the palette. For example, with 9 shades and count=3 it returns
-    /// `[1, 4, 7]`.
+    /// With an order, the labels keep the order in which they are listed in the configuration.
+    /// Without one, the labels are sorted by name and get the shades from light to dark.
+    #[builder(default, setter(into))]
+    #[getset(get_copy = "pub")]
+    order: Option<Order>,
+
+    /// The version of Tailwind CSS whose palette is used
+    #[builder(default)]
+    #[getset(get_copy = "pub")]
+    version: TailwindVersion,
+}
+
+impl Shade {
+    /// Create a new shade
     ///
-    /// If more colors are requested than available shades, the indices will simply repeat
-    /// the palette from the beginning: `[0, 1, 2, ...]`.
-    fn centered_indices(&self, count: usize) -> Vec<usize> {
-        // If the count is zero, exit early and return an empty vector
-        if count == 0 {
+    /// Returns `None` if Tailwind does not have a shade with this number.
+    pub fn new(shade: u16) -> Option<Self> {
+        SHADES.contains(&shade).then_some(Self(shade))
+    }
+
+    /// Returns the number of the shade
+    pub fn get(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the position of the shade in the palette
+    fn index(&self) -> usize {
+        SHADES
+            .iter()
+            .position(|shade| *shade == self.0)
+            .expect("shades are checked when they are created")
+    }
+}
+
+impl JsonSchema for Shade {
+    fn schema_name() -> Cow<'static, str> {
+        "Shade".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "description": "A shade of a Tailwind color",
+            "enum": SHADES,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Shade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let shade = u16::deserialize(deserializer)?;
+
+        Self::new(shade).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "shade `{shade}` is not one of {}",
+                SHADES.map(|shade| shade.to_string()).join(", ")
+            ))
+        })
+    }
+}
+
+impl TailwindVersion {
+    /// Returns the major version of Tailwind CSS
+    pub fn number(&self) -> u8 {
+        match self {
+            TailwindVersion::V3 => 3,
+            TailwindVersion::V4 => 4,
+        }
+    }
+}
+
+impl JsonSchema for TailwindVersion {
+    fn schema_name() -> Cow<'static, str> {
+        "TailwindVersion".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "description": "The version of Tailwind CSS whose palettes are used, which defaults to 3",
+            "enum": VERSIONS,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for TailwindVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            3 => Ok(TailwindVersion::V3),
+            4 => Ok(TailwindVersion::V4),
+            version => Err(serde::de::Error::custom(format!(
+                "Tailwind version `{version}` is not supported, expected 3 or 4"
+            ))),
+        }
+    }
+}
+
+impl Order {
+    /// Arrange the items, which are sorted from light to dark, in this order
+    fn arrange<T>(&self, mut items: Vec<T>) -> Vec<T> {
+        match self {
+            Order::LightToDark => items,
+            Order::DarkToLight => {
+                items.reverse();
+                items
+            }
+            Order::Alternating => {
+                let mut items = VecDeque::from(items);
+                let mut arranged = Vec::with_capacity(items.len());
+
+                while let Some(light) = items.pop_front() {
+                    arranged.push(light);
+
+                    if let Some(dark) = items.pop_back() {
+                        arranged.push(dark);
+                    }
+                }
+
+                arranged
+            }
+        }
+    }
+}
+
+impl TailwindScale {
+    /// Returns the positions of the shades to pick from, sorted from light to dark
+    fn available(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = match (&self.shades, self.range) {
+            (Some(shades), _) => shades.iter().map(Shade::index).collect(),
+            (None, Some([lightest, darkest])) => (lightest.index()..=darkest.index()).collect(),
+            (None, None) => DEFAULT_SHADES.collect(),
+        };
+
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+    }
+}
+
+impl From<Tailwind> for TailwindScale {
+    fn from(color: Tailwind) -> Self {
+        Self::builder().color(color).build()
+    }
+}
+
+impl Generate for TailwindScale {
+    fn generate(&self, count: usize) -> Vec<Color> {
+        let colors = self.color.colors(self.version);
+        let available = self.available();
+
+        if available.is_empty() {
             return Vec::new();
         }
 
-        // If more colors are requested than available shades, repeat the palette from the start
-        if count > SHADES_COUNT {
-            return (0..count).map(|i| i % SHADES_COUNT).collect();
+        // Repeated shades start over at the lightest shade, so sort them from light to dark before
+        // they are arranged in the order of the labels
+        let mut indices = centered_indices(count, available.len());
+        if self.order.is_some() {
+            indices.sort_unstable();
         }
 
-        // Otherwise, distribute picks evenly across the palette using
-        // floor((k + 0.5) * N / M), implemented as integer math: ((2*k + 1) * N) / (2*M)
-        (0..count)
-            .map(|k| ((2 * k + 1) * SHADES_COUNT) / (2 * count))
-            .collect()
+        let picks = indices
+            .into_iter()
+            .map(|index| colors[available[index]])
+            .collect();
+
+        self.order.unwrap_or_default().arrange(picks)
     }
+}
 
+impl Tailwind {
     /// Get the colors for the Tailwind color palette
     ///
-    /// This method returns a list of colors for each Tailwind color variant. A subset of colors has
-    /// been picked from Tailwind's palette, ignoring the brightest and darkest variants because
-    /// they are too challenging to differentiate from other colors.
-    fn colors(&self) -> [Color; SHADES_COUNT] {
+    /// This method returns all shades of the color from light to dark, as they are defined in the
+    /// given version of Tailwind CSS.
+    fn colors(&self, version: TailwindVersion) -> [Color; SHADES_COUNT] {
+        match version {
+            TailwindVersion::V3 => self.hex().map(Color::from_hex),
+            TailwindVersion::V4 => self.oklch().map(|(lightness, chroma, hue)| {
+                color::Oklch::new(lightness, chroma, hue).to_color()
+            }),
+        }
+    }
+
+    /// Returns the shades of the color in Tailwind CSS v3, which are defined in hex
+    fn hex(&self) -> [u32; SHADES_COUNT] {
         match self {
             Tailwind::Red => [
-                "#fee2e2", "#fecaca", "#fca5a5", "#f87171", "#ef4444", "#dc2626", "#b91c1c",
-                "#991b1b", "#7f1d1d",
+                0xfef2f2, 0xfee2e2, 0xfecaca, 0xfca5a5, 0xf87171, 0xef4444, 0xdc2626, 0xb91c1c,
+                0x991b1b, 0x7f1d1d, 0x450a0a,
             ],
             Tailwind::Orange => [
-                "#ffedd5", "#fed7aa", "#fdba74", "#fb923c", "#f97316", "#ea580c", "#c2410c",
-                "#9a3412", "#7c2d12",
+                0xfff7ed, 0xffedd5, 0xfed7aa, 0xfdba74, 0xfb923c, 0xf97316, 0xea580c, 0xc2410c,
+                0x9a3412, 0x7c2d12, 0x431407,
             ],
             Tailwind::Amber => [
-                "#fef3c7", "#fde68a", "#fcd34d", "#fbbf24", "#f59e0b", "#d97706", "#b45309",
-                "#92400e", "#78350f",
+                0xfffbeb, 0xfef3c7, 0xfde68a, 0xfcd34d, 0xfbbf24, 0xf59e0b, 0xd97706, 0xb45309,
+                0x92400e, 0x78350f, 0x451a03,
             ],
             Tailwind::Yellow => [
-                "#fef9c3", "#fef08a", "#fde047", "#facc15", "#eab308", "#ca8a04", "#a16207",
-                "#854d0e", "#713f12",
+                0xfefce8, 0xfef9c3, 0xfef08a, 0xfde047, 0xfacc15, 0xeab308, 0xca8a04, 0xa16207,
+                0x854d0e, 0x713f12, 0x422006,
             ],
             Tailwind::Lime => [
-                "#ecfccb", "#d9f99d", "#bef264", "#a3e635", "#84cc16", "#65a30d", "#4d7c0f",
-                "#3f6212", "#365314",
+                0xf7fee7, 0xecfccb, 0xd9f99d, 0xbef264, 0xa3e635, 0x84cc16, 0x65a30d, 0x4d7c0f,
+                0x3f6212, 0x365314, 0x1a2e05,
             ],
             Tailwind::Green => [
-                "#dcfce7", "#bbf7d0", "#86efac", "#4ade80", "#22c55e", "#16a34a", "#15803d",
-                "#166534", "#14532d",
+                0xf0fdf4, 0xdcfce7, 0xbbf7d0, 0x86efac, 0x4ade80, 0x22c55e, 0x16a34a, 0x15803d,
+                0x166534, 0x14532d, 0x052e16,
             ],
             Tailwind::Emerald => [
-                "#d1fae5", "#a7f3d0", "#6ee7b7", "#34d399", "#10b981", "#059669", "#047857",
-                "#065f46", "#064e3b",
+                0xecfdf5, 0xd1fae5, 0xa7f3d0, 0x6ee7b7, 0x34d399, 0x10b981, 0x059669, 0x047857,
+                0x065f46, 0x064e3b, 0x022c22,
             ],
             Tailwind::Teal => [
-                "#ccfbf1", "#99f6e4", "#5eead4", "#2dd4bf", "#14b8a6", "#0d9488", "#0f766e",
-                "#115e59", "#134e4a",
+                0xf0fdfa, 0xccfbf1, 0x99f6e4, 0x5eead4, 0x2dd4bf, 0x14b8a6, 0x0d9488, 0x0f766e,
+                0x115e59, 0x134e4a, 0x042f2e,
             ],
             Tailwind::Cyan => [
-                "#cffafe", "#a5f3fc", "#67e8f9", "#22d3ee", "#06b6d4", "#0891b2", "#0e7490",
-                "#155e75", "#164e63",
+                0xecfeff, 0xcffafe, 0xa5f3fc, 0x67e8f9, 0x22d3ee, 0x06b6d4, 0x0891b2, 0x0e7490,
+                0x155e75, 0x164e63, 0x083344,
             ],
             Tailwind::Sky => [
-                "#e0f2fe", "#bae6fd", "#7dd3fc", "#38bdf8", "#0ea5e9", "#0284c7", "#0369a1",
-                "#075985", "#0c4a6e",
+                0xf0f9ff, 0xe0f2fe, 0xbae6fd, 0x7dd3fc, 0x38bdf8, 0x0ea5e9, 0x0284c7, 0x0369a1,
+                0x075985, 0x0c4a6e, 0x082f49,
             ],
             Tailwind::Blue => [
-                "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb", "#1d4ed8",
-                "#1e40af", "#1e3a8a",
+                0xeff6ff, 0xdbeafe, 0xbfdbfe, 0x93c5fd, 0x60a5fa, 0x3b82f6, 0x2563eb, 0x1d4ed8,
+                0x1e40af, 0x1e3a8a, 0x172554,
             ],
             Tailwind::Indigo => [
-                "#e0e7ff", "#c7d2fe", "#a5b4fc", "#818cf8", "#6366f1", "#4f46e5", "#4338ca",
-                "#3730a3", "#312e81",
+                0xeef2ff, 0xe0e7ff, 0xc7d2fe, 0xa5b4fc, 0x818cf8, 0x6366f1, 0x4f46e5, 0x4338ca,
+                0x3730a3, 0x312e81, 0x1e1b4b,
             ],
             Tailwind::Violet => [
-                "#ede9fe", "#ddd6fe", "#c4b5fd", "#a78bfa", "#8b5cf6", "#7c3aed", "#6d28d9",
-                "#5b21b6", "#4c1d95",
+                0xf5f3ff, 0xede9fe, 0xddd6fe, 0xc4b5fd, 0xa78bfa, 0x8b5cf6, 0x7c3aed, 0x6d28d9,
+                0x5b21b6, 0x4c1d95, 0x2e1065,
             ],
             Tailwind::Purple => [
-                "#f3e8ff", "#e9d5ff", "#d8b4fe", "#c084fc", "#a855f7", "#9333ea", "#7e22ce",
-                "#6b21a8", "#581c87",
+                0xfaf5ff, 0xf3e8ff, 0xe9d5ff, 0xd8b4fe, 0xc084fc, 0xa855f7, 0x9333ea, 0x7e22ce,
+                0x6b21a8, 0x581c87, 0x3b0764,
             ],
             Tailwind::Fuchsia => [
-                "#fae8ff", "#f5d0fe", "#f0abfc", "#e879f9", "#d946ef", "#c026d3", "#a21caf",
-                "#86198f", "#701a75",
+                0xfdf4ff, 0xfae8ff, 0xf5d0fe, 0xf0abfc, 0xe879f9, 0xd946ef, 0xc026d3, 0xa21caf,
+                0x86198f, 0x701a75, 0x4a044e,
             ],
             Tailwind::Pink => [
-                "#fce7f3", "#fbcfe8", "#f9a8d4", "#f472b6", "#ec4899", "#db2777", "#be185d",
-                "#9d174d", "#831843",
+                0xfdf2f8, 0xfce7f3, 0xfbcfe8, 0xf9a8d4, 0xf472b6, 0xec4899, 0xdb2777, 0xbe185d,
+                0x9d174d, 0x831843, 0x500724,
             ],
             Tailwind::Rose => [
-                "#ffe4e6", "#fecdd3", "#fda4af", "#fb7185", "#f43f5e", "#e11d48", "#be123c",
-                "#9f1239", "#881337",
+                0xfff1f2, 0xffe4e6, 0xfecdd3, 0xfda4af, 0xfb7185, 0xf43f5e, 0xe11d48, 0xbe123c,
+                0x9f1239, 0x881337, 0x4c0519,
             ],
             Tailwind::Slate => [
-                "#f1f5f9", "#e2e8f0", "#cbd5e1", "#94a3b8", "#64748b", "#475569", "#334155",
-                "#1e293b", "#0f172a",
+                0xf8fafc, 0xf1f5f9, 0xe2e8f0, 0xcbd5e1, 0x94a3b8, 0x64748b, 0x475569, 0x334155,
+                0x1e293b, 0x0f172a, 0x020617,
             ],
             Tailwind::Gray => [
-                "#f3f4f6", "#e5e7eb", "#d1d5db", "#9ca3af", "#6b7280", "#4b5563", "#374151",
-                "#1f2937", "#111827",
+                0xf9fafb, 0xf3f4f6, 0xe5e7eb, 0xd1d5db, 0x9ca3af, 0x6b7280, 0x4b5563, 0x374151,
+                0x1f2937, 0x111827, 0x030712,
             ],
             Tailwind::Zinc => [
-                "#f4f4f5", "#e4e4e7", "#d4d4d8", "#a1a1aa", "#71717a", "#52525b", "#3f3f46",
-                "#27272a", "#18181b",
+                0xfafafa, 0xf4f4f5, 0xe4e4e7, 0xd4d4d8, 0xa1a1aa, 0x71717a, 0x52525b, 0x3f3f46,
+                0x27272a, 0x18181b, 0x09090b,
             ],
-            Tailwind::Natural => [
-                "#f5f5f5", "#e5e5e5", "#d4d4d4", "#a3a3a3", "#737373", "#525252", "#404040",
-                "#262626", "#171717",
+            #[allow(deprecated)]
+            Tailwind::Neutral | Tailwind::Natural => [
+                0xfafafa, 0xf5f5f5, 0xe5e5e5, 0xd4d4d4, 0xa3a3a3, 0x737373, 0x525252, 0x404040,
+                0x262626, 0x171717, 0x0a0a0a,
             ],
             Tailwind::Stone => [
-                "#f5f5f4", "#e7e5e4", "#d6d3d1", "#a8a29e", "#78716c", "#57534e", "#44403c",
-                "#292524", "#1c1917",
+                0xfafaf9, 0xf5f5f4, 0xe7e5e4, 0xd6d3d1, 0xa8a29e, 0x78716c, 0x57534e, 0x44403c,
+                0x292524, 0x1c1917, 0x0c0a09,
             ],
         }
-        .map(Into::into)
     }
-}
 
-impl Generate for Tailwind {
-    fn generate(&self, count: usize) -> Vec<Color> {
-        let colors = self.colors();
-        let indices = self.centered_indices(count);
-
-        indices
-            .into_iter()
-            .map(|index| colors[index % colors.len()].clone())
-            .collect()
+    /// Returns the shades of the color in Tailwind CSS v4 as lightness, chroma, and hue in OKLCH
+    fn oklch(&self) -> [(f64, f64, f64); SHADES_COUNT] {
+        match self {
+            Tailwind::Red => [
+                (0.971, 0.013, 17.38),
+                (0.936, 0.032, 17.717),
+                (0.885, 0.062, 18.334),
+                (0.808, 0.114, 19.571),
+                (0.704, 0.191, 22.216),
+                (0.637, 0.237, 25.331),
+                (0.577, 0.245, 27.325),
+                (0.505, 0.213, 27.518),
+                (0.444, 0.177, 26.899),
+                (0.396, 0.141, 25.723),
+                (0.258, 0.092, 26.042),
+            ],
+            Tailwind::Orange => [
+                (0.98, 0.016, 73.684),
+                (0.954, 0.038, 75.164),
+                (0.901, 0.076, 70.697),
+                (0.837, 0.128, 66.29),
+                (0.75, 0.183, 55.934),
+                (0.705, 0.213, 47.604),
+                (0.646, 0.222, 41.116),
+                (0.553, 0.195, 38.402),
+                (0.47, 0.157, 37.304),
+                (0.408, 0.123, 38.172),
+                (0.266, 0.079, 36.259),
+            ],
+            Tailwind::Amber => [
+                (0.987, 0.022, 95.277),
+                (0.962, 0.059, 95.617),
+                (0.924, 0.12, 95.746),
+                (0.879, 0.169, 91.605),
+                (0.828, 0.189, 84.429),
+                (0.769, 0.188, 70.08),
+                (0.666, 0.179, 58.318),
+                (0.555, 0.163, 48.998),
+                (0.473, 0.137, 46.201),
+                (0.414, 0.112, 45.904),
+                (0.279, 0.077, 45.635),
+            ],
+            Tailwind::Yellow => [
+                (0.987, 0.026, 102.212),
+                (0.973, 0.071, 103.193),
+                (0.945, 0.129, 101.54),
+                (0.905, 0.182, 98.111),
+                (0.852, 0.199, 91.936),
+                (0.795, 0.184, 86.047),
+                (0.681, 0.162, 75.834),
+                (0.554, 0.135, 66.442),
+                (0.476, 0.114, 61.907),
+                (0.421, 0.095, 57.708),
+                (0.286, 0.066, 53.813),
+            ],
+            Tailwind::Lime => [
+                (0.986, 0.031, 120.757),
+                (0.967, 0.067, 122.328),
+                (0.938, 0.127, 124.321),
+                (0.897, 0.196, 126.665),
+                (0.841, 0.238, 128.85),
+                (0.768, 0.233, 130.85),
+                (0.648, 0.2, 131.684),
+                (0.532, 0.157, 131.589),
+                (0.453, 0.124, 130.933),
+                (0.405, 0.101, 131.063),
+                (0.274, 0.072, 132.109),
+            ],
+            Tailwind::Green => [
+                (0.982, 0.018, 155.826),
+                (0.962, 0.044, 156.743),
+                (0.925, 0.084, 155.995),
+                (0.871, 0.15, 154.449),
+                (0.792, 0.209, 151.711),
+                (0.723, 0.219, 149.579),
+                (0.627, 0.194, 149.214),
+                (0.527, 0.154, 150.069),
+                (0.448, 0.119, 151.328),
+                (0.393, 0.095, 152.535),
+                (0.266, 0.065, 152.934),
+            ],
+            Tailwind::Emerald => [
+                (0.979, 0.021, 166.113),
+                (0.95, 0.052, 163.051),
+                (0.905, 0.093, 164.15),
+                (0.845, 0.143, 164.978),
+                (0.765, 0.177, 163.223),
+                (0.696, 0.17, 162.48),
+                (0.596, 0.145, 163.225),
+                (0.508, 0.118, 165.612),
+                (0.432, 0.095, 166.913),
+                (0.378, 0.077, 168.94),
+                (0.262, 0.051, 172.552),
+            ],
+            Tailwind::Teal => [
+                (0.984, 0.014, 180.72),
+                (0.953, 0.051, 180.801),
+                (0.91, 0.096, 180.426),
+                (0.855, 0.138, 181.071),
+                (0.777, 0.152, 181.912),
+                (0.704, 0.14, 182.503),
+                (0.6, 0.118, 184.704),
+                (0.511, 0.096, 186.391),
+                (0.437, 0.078, 188.216),
+                (0.386, 0.063, 188.416),
+                (0.277, 0.046, 192.524),
+            ],
+            Tailwind::Cyan => [
+                (0.984, 0.019, 200.873),
+                (0.956, 0.045, 203.388),
+                (0.917, 0.08, 205.041),
+                (0.865, 0.127, 207.078),
+                (0.789, 0.154, 211.53),
+                (0.715, 0.143, 215.221),
+                (0.609, 0.126, 221.723),
+                (0.52, 0.105, 223.128),
+                (0.45, 0.085, 224.283),
+                (0.398, 0.07, 227.392),
+                (0.302, 0.056, 229.695),
+            ],
+            Tailwind::Sky => [
+                (0.977, 0.013, 236.62),
+                (0.951, 0.026, 236.824),
+                (0.901, 0.058, 230.902),
+                (0.828, 0.111, 230.318),
+                (0.746, 0.16, 232.661),
+                (0.685, 0.169, 237.323),
+                (0.588, 0.158, 241.966),
+                (0.5, 0.134, 242.749),
+                (0.443, 0.11, 240.79),
+                (0.391, 0.09, 240.876),
+                (0.293, 0.066, 243.157),
+            ],
+            Tailwind::Blue => [
+                (0.97, 0.014, 254.604),
+                (0.932, 0.032, 255.585),
+                (0.882, 0.059, 254.128),
+                (0.809, 0.105, 251.813),
+                (0.707, 0.165, 254.624),
+                (0.623, 0.214, 259.815),
+                (0.546, 0.245, 262.881),
+                (0.488, 0.243, 264.376),
+                (0.424, 0.199, 265.638),
+                (0.379, 0.146, 265.522),
+                (0.282, 0.091, 267.935),
+            ],
+            Tailwind::Indigo => [
+                (0.962, 0.018, 272.314),
+                (0.93, 0.034, 272.788),
+                (0.87, 0.065, 274.039),
+                (0.785, 0.115, 274.713),
+                (0.673, 0.182, 276.935),
+                (0.585, 0.233, 277.117),
+                (0.511, 0.262, 276.966),
+                (0.457, 0.24, 277.023),
+                (0.398, 0.195, 277.366),
+                (0.359, 0.144, 278.697),
+                (0.257, 0.09, 281.288),
+            ],
+            Tailwind::Violet => [
+                (0.969, 0.016, 293.756),
+                (0.943, 0.029, 294.588),
+                (0.894, 0.057, 293.283),
+                (0.811, 0.111, 293.571),
+                (0.702, 0.183, 293.541),
+                (0.606, 0.25, 292.717),
+                (0.541, 0.281, 293.009),
+                (0.491, 0.27, 292.581),
+                (0.432, 0.232, 292.759),
+                (0.38, 0.189, 293.745),
+                (0.283, 0.141, 291.089),
+            ],
+            Tailwind::Purple => [
+                (0.977, 0.014, 308.299),
+                (0.946, 0.033, 307.174),
+                (0.902, 0.063, 306.703),
+                (0.827, 0.119, 306.383),
+                (0.714, 0.203, 305.504),
+                (0.627, 0.265, 303.9),
+                (0.558, 0.288, 302.321),
+                (0.496, 0.265, 301.924),
+                (0.438, 0.218, 303.724),
+                (0.381, 0.176, 304.987),
+                (0.291, 0.149, 302.717),
+            ],
+            Tailwind::Fuchsia => [
+                (0.977, 0.017, 320.058),
+                (0.952, 0.037, 318.852),
+                (0.903, 0.076, 319.62),
+                (0.833, 0.145, 321.434),
+                (0.74, 0.238, 322.16),
+                (0.667, 0.295, 322.15),
+                (0.591, 0.293, 322.896),
+                (0.518, 0.253, 323.949),
+                (0.452, 0.211, 324.591),
+                (0.401, 0.17, 325.612),
+                (0.293, 0.136, 325.661),
+            ],
+            Tailwind::Pink => [
+                (0.971, 0.014, 343.198),
+                (0.948, 0.028, 342.258),
+                (0.899, 0.061, 343.231),
+                (0.823, 0.12, 346.018),
+                (0.718, 0.202, 349.761),
+                (0.656, 0.241, 354.308),
+                (0.592, 0.249, 0.584),
+                (0.525, 0.223, 3.958),
+                (0.459, 0.187, 3.815),
+                (0.408, 0.153, 2.432),
+                (0.284, 0.109, 3.907),
+            ],
+            Tailwind::Rose => [
+                (0.969, 0.015, 12.422),
+                (0.941, 0.03, 12.58),
+                (0.892, 0.058, 10.001),
+                (0.81, 0.117, 11.638),
+                (0.712, 0.194, 13.428),
+                (0.645, 0.246, 16.439),
+                (0.586, 0.253, 17.585),
+                (0.514, 0.222, 16.935),
+                (0.455, 0.188, 13.697),
+                (0.41, 0.159, 10.272),
+                (0.271, 0.105, 12.094),
+            ],
+            Tailwind::Slate => [
+                (0.984, 0.003, 247.858),
+                (0.968, 0.007, 247.896),
+                (0.929, 0.013, 255.508),
+                (0.869, 0.022, 252.894),
+                (0.704, 0.04, 256.788),
+                (0.554, 0.046, 257.417),
+                (0.446, 0.043, 257.281),
+                (0.372, 0.044, 257.287),
+                (0.279, 0.041, 260.031),
+                (0.208, 0.042, 265.755),
+                (0.129, 0.042, 264.695),
+            ],
+            Tailwind::Gray => [
+                (0.985, 0.002, 247.839),
+                (0.967, 0.003, 264.542),
+                (0.928, 0.006, 264.531),
+                (0.872, 0.01, 258.338),
+                (0.707, 0.022, 261.325),
+                (0.551, 0.027, 264.364),
+                (0.446, 0.03, 256.802),
+                (0.373, 0.034, 259.733),
+                (0.278, 0.033, 256.848),
+                (0.21, 0.034, 264.665),
+                (0.13, 0.028, 261.692),
+            ],
+            Tailwind::Zinc => [
+                (0.985, 0.0, 0.0),
+                (0.967, 0.001, 286.375),
+                (0.92, 0.004, 286.32),
+                (0.871, 0.006, 286.286),
+                (0.705, 0.015, 286.067),
+                (0.552, 0.016, 285.938),
+                (0.442, 0.017, 285.786),
+                (0.37, 0.013, 285.805),
+                (0.274, 0.006, 286.033),
+                (0.21, 0.006, 285.885),
+                (0.141, 0.005, 285.823),
+            ],
+            #[allow(deprecated)]
+            Tailwind::Neutral | Tailwind::Natural => [
+                (0.985, 0.0, 0.0),
+                (0.97, 0.0, 0.0),
+                (0.922, 0.0, 0.0),
+                (0.87, 0.0, 0.0),
+                (0.708, 0.0, 0.0),
+                (0.556, 0.0, 0.0),
+                (0.439, 0.0, 0.0),
+                (0.371, 0.0, 0.0),
+                (0.269, 0.0, 0.0),
+                (0.205, 0.0, 0.0),
+                (0.145, 0.0, 0.0),
+            ],
+            Tailwind::Stone => [
+                (0.985, 0.001, 106.423),
+                (0.97, 0.001, 106.424),
+                (0.923, 0.003, 48.717),
+                (0.869, 0.005, 56.366),
+                (0.709, 0.01, 56.259),
+                (0.553, 0.013, 58.071),
+                (0.444, 0.011, 73.639),
+                (0.374, 0.01, 67.558),
+                (0.268, 0.007, 34.298),
+                (0.216, 0.006, 56.043),
+                (0.147, 0.004, 49.25),
+            ],
+        }
     }
 }
 
@@ -192,145 +716,260 @@ impl Generate for Tailwind {
 mod tests {
     use super::*;
 
-    #[test]
-    fn centered_indices_for_1() {
-        let tailwind = Tailwind::Red;
-
-        let indices = tailwind.centered_indices(1);
-
-        assert_eq!(indices, vec![4]);
+    fn shades(shades: &[u16]) -> Vec<Shade> {
+        shades
+            .iter()
+            .map(|shade| Shade::new(*shade).unwrap())
+            .collect()
     }
 
     #[test]
-    fn centered_indices_for_2() {
-        let tailwind = Tailwind::Red;
-
-        let indices = tailwind.centered_indices(2);
+    fn trait_generate_red() {
+        let scale = TailwindScale::from(Tailwind::Red);
+        let colors = scale.generate(5);
 
-        assert_eq!(indices, vec![2, 6]);
+        assert_eq!(
+            colors,
+            vec![
+                Color::from_hex(0xfee2e2),
+                Color::from_hex(0xfca5a5),
+                Color::from_hex(0xef4444),
+                Color::from_hex(0xb91c1c),
+                Color::from_hex(0x7f1d1d)
+            ]
+        );
     }
 
     #[test]
-    fn centered_indices_for_3() {
-        let tailwind = Tailwind::Red;
-
-        let indices = tailwind.centered_indices(3);
+    fn trait_generate_blue() {
+        let scale = TailwindScale::from(Tailwind::Blue);
+        let colors = scale.generate(12);
 
-        assert_eq!(indices, vec![1, 4, 7]);
+        assert_eq!(
+            colors,
+            vec![
+                Color::from_hex(0xdbeafe),
+                Color::from_hex(0xbfdbfe),
+                Color::from_hex(0x93c5fd),
+                Color::from_hex(0x60a5fa),
+                Color::from_hex(0x3b82f6),
+                Color::from_hex(0x2563eb),
+                Color::from_hex(0x1d4ed8),
+                Color::from_hex(0x1e40af),
+                Color::from_hex(0x1e3a8a),
+                Color::from_hex(0xdbeafe),
+                Color::from_hex(0xbfdbfe),
+                Color::from_hex(0x93c5fd),
+            ]
+        );
     }
 
     #[test]
-    fn centered_indices_for_4() {
-        let tailwind = Tailwind::Red;
+    fn shade_new() {
+        assert_eq!(Shade::new(300).map(|shade| shade.get()), Some(300));
+        assert_eq!(Shade::new(250), None);
+        assert_eq!(Shade::new(50).map(|shade| shade.get()), Some(50));
+        assert_eq!(Shade::new(950).map(|shade| shade.get()), Some(950));
+        assert_eq!(Shade::new(1000), None);
+    }
 
-        let indices = tailwind.centered_indices(4);
+    #[test]
+    fn shade_deserialize_unknown() {
+        let error = serde_yaml_ng::from_str::<Shade>("250").unwrap_err();
 
-        assert_eq!(indices, vec![1, 3, 5, 7]);
+        assert!(error.to_string().contains(
+            "shade `250` is not one of 50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950"
+        ));
     }
 
     #[test]
-    fn centered_indices_for_5() {
-        let tailwind = Tailwind::Red;
+    fn version_deserialize() {
+        assert_eq!(
+            serde_yaml_ng::from_str::<TailwindVersion>("4").unwrap(),
+            TailwindVersion::V4
+        );
 
-        let indices = tailwind.centered_indices(5);
+        let error = serde_yaml_ng::from_str::<TailwindVersion>("2").unwrap_err();
 
-        assert_eq!(indices, vec![0, 2, 4, 6, 8]);
+        assert!(
+            error
+                .to_string()
+                .contains("Tailwind version `2` is not supported, expected 3 or 4")
+        );
     }
 
     #[test]
-    fn centered_indices_for_6() {
-        let tailwind = Tailwind::Red;
+    #[allow(deprecated)]
+    fn trait_deserialize_natural() {
+        let natural: Tailwind = serde_yaml_ng::from_str("natural").unwrap();
+        let neutral: Tailwind = serde_yaml_ng::from_str("neutral").unwrap();
 
-        let indices = tailwind.centered_indices(6);
-
-        assert_eq!(indices, vec![0, 2, 3, 5, 6, 8]);
+        assert_eq!(natural, Tailwind::Natural);
+        assert_eq!(neutral, Tailwind::Neutral);
+        assert_eq!(
+            TailwindScale::from(natural).generate(5),
+            TailwindScale::from(neutral).generate(5)
+        );
     }
 
     #[test]
-    fn centered_indices_for_7() {
-        let tailwind = Tailwind::Red;
+    fn order_arrange() {
+        let items = vec![1, 2, 3, 4, 5];
 
-        let indices = tailwind.centered_indices(7);
-
-        assert_eq!(indices, vec![0, 1, 3, 4, 5, 7, 8]);
+        assert_eq!(
+            Order::LightToDark.arrange(items.clone()),
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            Order::DarkToLight.arrange(items.clone()),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert_eq!(Order::Alternating.arrange(items), vec![1, 5, 2, 4, 3]);
     }
 
     #[test]
-    fn centered_indices_for_8() {
-        let tailwind = Tailwind::Red;
-
-        let indices = tailwind.centered_indices(8);
+    fn scale_generate_with_shades() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .shades(Some(shades(&[700, 300, 500])))
+            .build();
 
-        assert_eq!(indices, vec![0, 1, 2, 3, 5, 6, 7, 8]);
+        assert_eq!(
+            scale.generate(3),
+            vec![
+                Color::from_hex(0xfca5a5),
+                Color::from_hex(0xef4444),
+                Color::from_hex(0xb91c1c),
+            ]
+        );
     }
 
     #[test]
-    fn centered_indices_for_9() {
-        let tailwind = Tailwind::Red;
-
-        let indices = tailwind.centered_indices(9);
+    fn scale_generate_with_range() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .range(Some([Shade(200), Shade(600)]))
+            .build();
 
-        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            scale.generate(2),
+            vec![Color::from_hex(0xfca5a5), Color::from_hex(0xef4444)]
+        );
     }
 
     #[test]
-    fn centered_indices_for_10() {
-        let tailwind = Tailwind::Red;
+    fn scale_generate_with_lightest_and_darkest_shades() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .range(Some([Shade(50), Shade(950)]))
+            .build();
+
+        assert_eq!(scale.generate(11).first(), Some(&Color::from_hex(0xfef2f2)));
+        assert_eq!(scale.generate(11).last(), Some(&Color::from_hex(0x450a0a)));
+    }
 
-        let indices = tailwind.centered_indices(10);
+    #[test]
+    fn scale_generate_v4() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Neutral)
+            .version(TailwindVersion::V4)
+            .build();
 
-        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 0]);
+        assert_eq!(
+            scale.generate(3),
+            vec![
+                Color::from_hex(0xe5e5e5),
+                Color::from_hex(0x737373),
+                Color::from_hex(0x262626),
+            ]
+        );
     }
 
     #[test]
-    fn centered_indices_for_18() {
-        let tailwind = Tailwind::Red;
+    fn scale_generate_v4_is_close_to_v3() {
+        for color in [
+            Tailwind::Red,
+            Tailwind::Green,
+            Tailwind::Blue,
+            Tailwind::Stone,
+        ] {
+            let v3 = color.colors(TailwindVersion::V3);
+            let v4 = color.colors(TailwindVersion::V4);
+
+            for (v3, v4) in v3.iter().zip(v4.iter()) {
+                let (v3, v4) = (v3.to_oklch(), v4.to_oklch());
+
+                assert!((v3.lightness() - v4.lightness()).abs() < 0.03);
+            }
+        }
+    }
 
-        let indices = tailwind.centered_indices(18);
+    #[test]
+    fn scale_generate_dark_to_light() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .shades(Some(shades(&[300, 500, 700])))
+            .order(Order::DarkToLight)
+            .build();
 
         assert_eq!(
-            indices,
-            vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 0, 1, 2, 3, 4, 5, 6, 7, 8]
+            scale.generate(3),
+            vec![
+                Color::from_hex(0xb91c1c),
+                Color::from_hex(0xef4444),
+                Color::from_hex(0xfca5a5),
+            ]
         );
     }
 
     #[test]
-    fn trait_generate_red() {
-        let tailwind = Tailwind::Red;
-        let colors = tailwind.generate(5);
+    fn scale_generate_alternating() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .order(Order::Alternating)
+            .build();
 
         assert_eq!(
-            colors,
+            scale.generate(3),
             vec![
-                "#fee2e2".into(),
-                "#fca5a5".into(),
-                "#ef4444".into(),
-                "#b91c1c".into(),
-                "#7f1d1d".into()
+                Color::from_hex(0xfecaca),
+                Color::from_hex(0x991b1b),
+                Color::from_hex(0xef4444),
             ]
         );
     }
 
     #[test]
-    fn trait_generate_blue() {
-        let tailwind = Tailwind::Blue;
-        let colors = tailwind.generate(12);
+    fn scale_generate_dark_to_light_with_repeated_shades() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .order(Order::DarkToLight)
+            .build();
+
+        let colors = scale.generate(10);
+
+        assert_eq!(colors[0], Color::from_hex(0x7f1d1d));
+        assert_eq!(colors[1], Color::from_hex(0x991b1b));
+        assert_eq!(colors[8], Color::from_hex(0xfee2e2));
+        assert_eq!(colors[9], Color::from_hex(0xfee2e2));
+    }
+
+    #[test]
+    fn scale_generate_alternating_with_repeated_shades() {
+        let scale = TailwindScale::builder()
+            .color(Tailwind::Red)
+            .order(Order::Alternating)
+            .build();
+
+        let colors = scale.generate(10);
 
         assert_eq!(
-            colors,
-            vec![
-                "#dbeafe".into(),
-                "#bfdbfe".into(),
-                "#93c5fd".into(),
-                "#60a5fa".into(),
-                "#3b82f6".into(),
-                "#2563eb".into(),
-                "#1d4ed8".into(),
-                "#1e40af".into(),
-                "#1e3a8a".into(),
-                "#dbeafe".into(),
-                "#bfdbfe".into(),
-                "#93c5fd".into(),
+            colors[..4],
+            [
+                Color::from_hex(0xfee2e2),
+                Color::from_hex(0x7f1d1d),
+                Color::from_hex(0xfee2e2),
+                Color::from_hex(0x991b1b),
             ]
         );
     }
@@ -1,14 +1,261 @@
 //! Configuration for Labelflair
+//!
+//! The configuration file can declare the version of its format with a top-level `version` key.
+//! Files without the key are treated as version 1, which was the only format before the key was
+//! introduced. [`Configuration`] reads the key and parses the file with the matching version.
+
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use schemars::generate::SchemaSettings;
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer};
+
+use crate::Error;
 
 use self::v1::ConfigV1;
 
+pub use self::migrate::migrate;
+
 pub mod v1;
 
+mod migrate;
+
 /// Configuration for Labelflair
 ///
 /// This enum represents the configuration for Labelflair. The configuration is versioned to allow
 /// for future extensions and changes without breaking existing configurations.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Configuration {
     /// Version 1
     V1(ConfigV1),
 }
+
+/// The versions of the configuration format
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Version {
+    /// Version 1
+    #[default]
+    V1,
+}
+
+/// The top-level keys that are needed to pick the version of the configuration
+#[derive(Clone, Debug, Default, Deserialize)]
+struct Header {
+    /// The version of the configuration format
+    #[serde(default)]
+    version: Version,
+}
+
+/// Generate the JSON Schema for the latest version of the configuration
+///
+/// The schema is derived from the types of the configuration and their documentation, so it always
+/// matches the format that Labelflair accepts. It follows draft 7 of JSON Schema, which is supported
+/// by editors and tools like [Taplo](https://taplo.tamasfe.dev).
+pub fn schema() -> Schema {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    let version = generator.subschema_for::<Version>();
+    let mut schema = generator.into_root_schema_for::<ConfigV1>();
+
+    schema.insert("title".into(), "Labelflair configuration".into());
+
+    if let Some(properties) = schema
+        .get_mut("properties")
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.insert("version".into(), version.to_value());
+    }
+
+    schema
+}
+
+impl Configuration {
+    /// Returns the version of the configuration
+    pub fn version(&self) -> Version {
+        match self {
+            Configuration::V1(_) => Version::V1,
+        }
+    }
+
+    /// Converts the configuration into the latest version
+    pub fn into_latest(self) -> ConfigV1 {
+        match self {
+            Configuration::V1(config) => config,
+        }
+    }
+}
+
+impl FromStr for Configuration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let header: Header = toml::from_str(s)?;
+
+        match header.version {
+            Version::V1 => Ok(Configuration::V1(s.parse()?)),
+        }
+    }
+}
+
+impl Version {
+    /// The latest version of the configuration format
+    pub const LATEST: Version = Version::V1;
+
+    /// Returns the number of the version as it is written in the configuration
+    pub fn number(&self) -> i64 {
+        match self {
+            Version::V1 => 1,
+        }
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl JsonSchema for Version {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Version".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "description": "The version of the configuration format, which defaults to 1",
+            "enum": [Version::LATEST.number()],
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = i64::deserialize(deserializer)?;
+
+        match number {
+            1 => Ok(Version::V1),
+            _ => Err(serde::de::Error::custom(format!(
+                "unsupported version {number}, the latest version is {}",
+                Version::LATEST
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn trait_from_str_without_version() {
+        let toml = indoc! {r#"
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "#};
+
+        let configuration: Configuration = toml.parse().unwrap();
+
+        assert_eq!(configuration.version(), Version::V1);
+        assert_eq!(configuration.into_latest().groups().len(), 1);
+    }
+
+    #[test]
+    fn trait_from_str_with_version() {
+        let toml = indoc! {r#"
+            version = 1
+
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "#};
+
+        let configuration: Configuration = toml.parse().unwrap();
+
+        assert_eq!(configuration.version(), Version::V1);
+    }
+
+    #[test]
+    fn trait_from_str_with_unsupported_version() {
+        let error = "version = 2".parse::<Configuration>().unwrap_err();
+
+        let Error::Parse(error) = error else {
+            panic!("expected a parse error, got {error:?}");
+        };
+
+        assert_eq!(
+            error.message(),
+            "unsupported version 2, the latest version is 1"
+        );
+        assert_eq!(error.span(), Some(10..11));
+    }
+
+    #[test]
+    fn schema_describes_configuration() {
+        let schema = schema().to_value();
+
+        assert_eq!(schema["title"], "Labelflair configuration");
+        assert_eq!(schema["properties"]["version"]["enum"][0], 1);
+        assert!(schema["properties"]["group"].is_object());
+        assert!(schema["properties"]["label"].is_object());
+    }
+
+    #[test]
+    fn schema_lists_tailwind_colors() {
+        let schema = schema().to_value();
+        let tailwind = &schema["definitions"]["Tailwind"]["oneOf"];
+        let colors = tailwind[0]["enum"].as_array().unwrap();
+
+        assert!(colors.iter().any(|color| color == "red"));
+        assert!(colors.iter().any(|color| color == "stone"));
+        assert_eq!(tailwind[1]["const"], "natural");
+        assert_eq!(tailwind[1]["deprecated"], true);
+    }
+
+    #[test]
+    fn schema_includes_doc_comments() {
+        let schema = schema().to_value();
+
+        assert!(
+            schema["definitions"]["Group"]["properties"]["prefix"]["description"]
+                .as_str()
+                .unwrap()
+                .contains("An optional prefix for the labels in this group")
+        );
+    }
+
+    #[test]
+    fn version_number() {
+        assert_eq!(Version::LATEST.number(), 1);
+        assert_eq!(Version::V1.to_string(), "1");
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Configuration>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Configuration>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Configuration>();
+    }
+}
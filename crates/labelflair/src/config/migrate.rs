@@ -0,0 +1,140 @@
+//! Migrate a configuration to the latest version
+//!
+//! Migrations edit the TOML document in place instead of serializing the parsed configuration, so
+//! that comments, formatting, and the order of the keys in the file are preserved.
+
+use toml_edit::{DocumentMut, value};
+
+use crate::Error;
+
+use super::{Configuration, Version};
+
+/// Migrate the configuration in the TOML document to the latest version
+///
+/// The configuration is parsed first to ensure that it is valid. Every change between its version
+/// and the latest version is then applied to the document, and the `version` key is set to the
+/// latest version. Configurations that are already up-to-date are returned unchanged.
+pub fn migrate(source: &str) -> Result<String, Error> {
+    let configuration: Configuration = source.parse()?;
+
+    let mut document: DocumentMut = source.parse().map_err(|error: toml_edit::TomlError| {
+        Error::Parse(serde::de::Error::custom(error.message()))
+    })?;
+
+    // Version 1 is the latest version, so no changes need to be applied yet. Future versions add a
+    // step for each version here.
+    match configuration.version() {
+        Version::V1 => {}
+    }
+
+    match document.get("version").and_then(|item| item.as_integer()) {
+        Some(version) if version == Version::LATEST.number() => {}
+        Some(_) => {
+            document.insert("version", value(Version::LATEST.number()));
+        }
+        None => return Ok(insert_version(&document.to_string())),
+    }
+
+    Ok(document.to_string())
+}
+
+/// Insert the `version` key after the comments at the top of the document
+///
+/// The comments at the top of a file usually describe the whole file, so the key is inserted
+/// between them and the first key or table. The comments end at the first line that is not a
+/// comment, e.g. a blank line.
+fn insert_version(document: &str) -> String {
+    let length: usize = document
+        .split_inclusive('\n')
+        .take_while(|line| line.trim_start().starts_with('#'))
+        .map(str::len)
+        .sum();
+    let (header, rest) = document.split_at(length);
+    let separator = if header.is_empty() || header.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+
+    format!(
+        "{header}{separator}version = {}\n{rest}",
+        Version::LATEST.number()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn migrate_adds_version() {
+        let toml = indoc! {r#"
+            # Labels for the project
+
+            [[group]]
+            prefix = "C-" # Categories
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "#};
+
+        let migrated = migrate(toml).unwrap();
+
+        assert_eq!(
+            migrated,
+            indoc! {r#"
+                # Labels for the project
+                version = 1
+
+                [[group]]
+                prefix = "C-" # Categories
+                colors = { tailwind = "red" }
+                labels = ["bug"]
+            "#}
+        );
+    }
+
+    #[test]
+    fn migrate_adds_version_before_keys() {
+        let toml = indoc! {r#"
+            min_contrast = 4.5
+
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "#};
+
+        let migrated = migrate(toml).unwrap();
+
+        assert_eq!(migrated, format!("version = 1\n{toml}"));
+    }
+
+    #[test]
+    fn migrate_adds_version_after_comments_without_newline() {
+        assert_eq!(migrate("# Labels").unwrap(), "# Labels\nversion = 1\n");
+    }
+
+    #[test]
+    fn migrate_without_changes() {
+        let toml = indoc! {r#"
+            # Labels for the project
+            version = 1
+
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "#};
+
+        let migrated = migrate(toml).unwrap();
+
+        assert_eq!(migrated, toml);
+    }
+
+    #[test]
+    fn migrate_with_invalid_configuration() {
+        let error = migrate("version = 2").unwrap_err();
+
+        assert!(matches!(error, Error::Parse(_)));
+    }
+}
@@ -2,42 +2,91 @@
 //!
 //! This module defines the configuration structure for Labelflair version 1.
 
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use getset::Getters;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use typed_builder::TypedBuilder;
 
+use crate::Error;
+use crate::colors::{Palette, PaletteName};
 use crate::label::Label;
 
 pub use self::group::*;
 pub use self::label_variant::*;
+pub use self::min_contrast::*;
 
 mod group;
 mod label_variant;
+mod min_contrast;
+mod validate;
 
 /// Configuration for Labelflair version 1
 ///
 /// This struct represents the configuration for Labelflair version 1. It contains a list of
-/// individual labels and a map of label groups, where each [`Group`] is identified by a
-/// [`GroupName`]. Each group contains an optional prefix, a color generator, and a list of labels.
+/// individual labels and a list of label groups. Each [`Group`] contains an optional prefix, a
+/// color generator, and a list of labels. An optional minimum contrast ratio enforces that the
+/// names of all labels are readable.
+///
+/// Groups can use the [`Palette`]s that are defined in the configuration. The palettes are looked
+/// up by their name when the labels are generated, and [`ConfigV1::validate`] reports unknown
+/// names.
 #[derive(
-    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize, TypedBuilder,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Getters,
+    Deserialize,
+    JsonSchema,
+    TypedBuilder,
 )]
 pub struct ConfigV1 {
+    /// The minimum contrast ratio of the text on every label
+    ///
+    /// Labels below the minimum make the configuration invalid. Without a minimum, labels below a
+    /// contrast ratio of 3 are only reported as warnings.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    min_contrast: Option<MinContrast>,
+
+    /// Palettes that groups can use by their name
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[schemars(with = "BTreeMap<String, Palette>")]
+    #[serde(default, rename = "palette")]
+    palettes: BTreeMap<PaletteName, Palette>,
+
     /// A list of individual labels
     #[getset(get = "pub")]
     #[serde(default, rename = "label")]
     labels: Vec<Label>,
 
-    /// A map of label groups
+    /// A list of label groups
     #[getset(get = "pub")]
     #[serde(default, rename = "group")]
     groups: Vec<Group>,
 }
 
+impl FromStr for ConfigV1 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
 
+    use crate::color::Color;
     use crate::colors::{Colors, Tailwind};
 
     use super::*;
@@ -62,7 +111,7 @@ mod tests {
             .labels(vec![
                 Label::builder()
                     .name("good first issue")
-                    .color("#4ade80")
+                    .color(Color::from_hex(0x4ade80))
                     .description(Some("Good issue for newcomers".into()))
                     .aliases(vec!["help wanted".into()])
                     .build(),
@@ -70,13 +119,15 @@ mod tests {
             .groups(vec![
                 Group::builder()
                     .prefix(Prefix::new("C-"))
-                    .colors(Colors::Tailwind(Tailwind::Red))
+                    .colors(Colors::Tailwind(Tailwind::Red.into()))
                     .labels(vec![
                         LabelVariant::Name("bug".into()),
                         LabelVariant::WithDescription {
                             name: "feature".into(),
                             description: Some("A new feature".into()),
                             aliases: Vec::new(),
+                            priority: None,
+                            delete_after: None,
                         },
                     ])
                     .build(),
@@ -86,6 +137,84 @@ mod tests {
         assert_eq!(config, expected);
     }
 
+    #[test]
+    fn trait_from_str() {
+        let config: ConfigV1 = indoc! {r##"
+            [[label]]
+            name = "good first issue"
+            color = "#4ade80"
+        "##}
+        .parse()
+        .unwrap();
+
+        assert_eq!(config.labels().len(), 1);
+        assert!(config.groups().is_empty());
+    }
+
+    #[test]
+    fn trait_from_str_with_min_contrast() {
+        let config: ConfigV1 = indoc! {r##"
+            min_contrast = 4.5
+        "##}
+        .parse()
+        .unwrap();
+
+        assert_eq!(*config.min_contrast(), MinContrast::new(4.5));
+    }
+
+    #[test]
+    fn trait_from_str_with_palette() {
+        let config: ConfigV1 = indoc! {r##"
+            [palette.brand-blue]
+            shades = ["#dbeafe", "#93c5fd", "#3b82f6", "#1d4ed8", "#1e3a8a"]
+
+            [[group]]
+            colors = { palette = "brand-blue" }
+            labels = ["bug", "feature", "docs"]
+        "##}
+        .parse()
+        .unwrap();
+
+        let colors: Vec<Color> = config.groups()[0]
+            .expand(config.palettes())
+            .unwrap()
+            .iter()
+            .map(|label| *label.color())
+            .collect();
+
+        assert_eq!(
+            colors,
+            vec![
+                Color::from_hex(0xdbeafe),
+                Color::from_hex(0x3b82f6),
+                Color::from_hex(0x1e3a8a),
+            ]
+        );
+    }
+
+    #[test]
+    fn trait_from_str_with_empty_palette() {
+        let error = indoc! {r##"
+            [palette.brand-blue]
+            shades = []
+        "##}
+        .parse::<ConfigV1>()
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("a palette needs at least one shade")
+        );
+    }
+
+    #[test]
+    fn trait_from_str_with_invalid_toml() {
+        let error = "[[label]\nname = 1".parse::<ConfigV1>().unwrap_err();
+
+        assert!(matches!(error, Error::Parse(_)));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
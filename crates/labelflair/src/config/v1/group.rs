@@ -5,13 +5,17 @@
 //! when generating the final labels. The color generator is used to generate colors for the labels
 //! in the group, ensuring a consistent color scheme across related labels.
 
-use getset::Getters;
+use std::collections::BTreeMap;
+
+use getset::{CopyGetters, Getters};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use typed_builder::TypedBuilder;
 use typed_fields::name;
 
-use crate::colors::{Colors, Generate};
-use crate::label::Label;
+use crate::Error;
+use crate::colors::{Colors, Palette, PaletteName};
+use crate::label::{Label, Target};
 
 use super::LabelVariant;
 
@@ -27,16 +31,35 @@ name!(
 
 /// A group of labels in Labelflair
 ///
-/// This struct represents a group of labels in Labelflair. Each group has an optional prefix, a
-/// color generator, and a list of labels. If a prefix is provided, it will be prepended to each
-/// label in the group.
+/// This struct represents a group of labels in Labelflair. Each group has an optional name and
+/// prefix, a color generator, and a list of labels. If a prefix is provided, it will be prepended
+/// to each label in the group.
 #[derive(
-    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Getters, Deserialize, TypedBuilder,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    CopyGetters,
+    Getters,
+    Deserialize,
+    JsonSchema,
+    TypedBuilder,
 )]
 pub struct Group {
+    /// An optional name for the group, which is used as its heading in the documentation
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[schemars(with = "Option<String>")]
+    #[serde(default)]
+    name: Option<GroupName>,
+
     /// An optional prefix for the labels in this group
     #[builder(setter(into))]
     #[getset(get = "pub")]
+    #[schemars(with = "String")]
     #[serde(default)]
     prefix: Option<Prefix>,
 
@@ -47,6 +70,43 @@ pub struct Group {
     /// A list of labels in this group
     #[getset(get = "pub")]
     labels: Vec<LabelVariant>,
+
+    /// Whether an issue can only have one label from this group
+    ///
+    /// Gitea and Forgejo turn labels whose names contain a `/` into scoped labels, and allow only one
+    /// label per scope on an issue if the labels are exclusive. The prefix of an exclusive group
+    /// must therefore end with a `/`, e.g. `C/`. Other platforms ignore this setting.
+    #[builder(default)]
+    #[getset(get_copy = "pub")]
+    #[serde(default)]
+    exclusive: bool,
+
+    /// Whether the labels are meant for issues, pull requests, or both
+    ///
+    /// GitHub does not restrict where labels can be used, but Prow's `label_sync` records the target
+    /// of each label. The default is `both`.
+    #[builder(default)]
+    #[getset(get_copy = "pub")]
+    #[serde(default)]
+    target: Target,
+
+    /// Who is expected to add the labels to issues and pull requests, e.g. `humans` or `prow`
+    ///
+    /// Prow's `label_sync` documents this for each label. Other platforms ignore this setting.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    added_by: Option<String>,
+
+    /// The repositories that the labels are limited to, in the format `owner/name`
+    ///
+    /// Prow's `label_sync` applies the labels of groups without repositories to every repository,
+    /// and the labels of other groups only to the listed repositories. Other platforms ignore this
+    /// setting.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    repos: Vec<String>,
 }
 
 impl Group {
@@ -54,33 +114,60 @@ impl Group {
     ///
     /// This method generates a list of GitHub Issues labels, each with a name and a color. It does
     /// this by generating a list of colors, optionally prefixing each label name, and then mapping
-    /// names and colors into [`Label`] instances.
-    pub fn expand(&self) -> Vec<Label> {
-        let colors = self.colors.generate(self.labels.len());
+    /// names and colors into [`Label`] instances. See [`Colors::sorts_labels`] for the order in
+    /// which the colors are assigned.
+    ///
+    /// Palettes that the group references are looked up in `palettes`. An error is returned if a
+    /// palette does not exist, or if the color generator returns fewer colors than the group has
+    /// labels.
+    pub fn expand(&self, palettes: &BTreeMap<PaletteName, Palette>) -> Result<Vec<Label>, Error> {
+        let colors = self.colors.generate(self.labels.len(), palettes)?;
+
+        if colors.len() < self.labels.len() {
+            return Err(Error::MissingColors {
+                expected: self.labels.len(),
+                actual: colors.len(),
+            });
+        }
+
         let prefix = self.prefix.clone().unwrap_or("".into());
 
         // Sort labels to ensure a nice color palette in GitHub's user interface
         let mut labels = self.labels.clone();
-        labels.sort();
+        if self.colors.sorts_labels() {
+            labels.sort();
+        }
 
-        labels
+        let labels = labels
             .iter()
-            .enumerate()
-            .map(|(i, label)| {
+            .zip(colors)
+            .map(|(label, color)| {
                 Label::builder()
                     .name(format!("{prefix}{label}"))
-                    .color(colors[i].clone())
+                    .color(color)
                     .description(label.description().cloned())
                     .aliases(label.aliases().cloned().unwrap_or_default())
+                    .priority(label.priority())
+                    .delete_after(label.delete_after().cloned())
+                    .exclusive(self.exclusive)
+                    .target(self.target)
+                    .added_by(self.added_by.clone())
+                    .repos(self.repos.clone())
                     .build()
             })
-            .collect()
+            .collect();
+
+        Ok(labels)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::colors::Tailwind;
+    use indoc::indoc;
+
+    use crate::color::Color;
+    use crate::colors::{PaletteRef, Tailwind, TailwindScale};
+    use crate::label::Timestamp;
 
     use super::*;
 
@@ -88,17 +175,23 @@ mod tests {
     fn expand() {
         let group = Group::builder()
             .prefix(Prefix::new("C-"))
-            .colors(Colors::Tailwind(Tailwind::Red))
+            .colors(Colors::Tailwind(Tailwind::Red.into()))
             .labels(vec![
                 LabelVariant::Name("bug".into()),
                 LabelVariant::Name("feature".into()),
             ])
             .build();
 
-        let labels = group.expand();
+        let labels = group.expand(&BTreeMap::new()).unwrap();
         let expected = vec![
-            Label::builder().name("C-bug").color("#fca5a5").build(),
-            Label::builder().name("C-feature").color("#b91c1c").build(),
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
         ];
 
         assert_eq!(labels, expected);
@@ -108,7 +201,7 @@ mod tests {
     fn expand_sorts_labels() {
         let group = Group::builder()
             .prefix(Prefix::new("C-"))
-            .colors(Colors::Tailwind(Tailwind::Red))
+            .colors(Colors::Tailwind(Tailwind::Red.into()))
             .labels(vec![
                 LabelVariant::Name("feature".into()),
                 LabelVariant::Name("alpha".into()),
@@ -116,15 +209,174 @@ mod tests {
             ])
             .build();
 
-        let labels = group.expand();
+        let labels = group.expand(&BTreeMap::new()).unwrap();
         let expected = vec![
-            Label::builder().name("C-alpha").color("#fecaca").build(),
-            Label::builder().name("C-bug").color("#ef4444").build(),
-            Label::builder().name("C-feature").color("#991b1b").build(),
+            Label::builder()
+                .name("C-alpha")
+                .color(Color::from_hex(0xfecaca))
+                .build(),
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xef4444))
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0x991b1b))
+                .build(),
         ];
 
         assert_eq!(labels, expected);
     }
+
+    #[test]
+    fn expand_keeps_label_order_of_gradient() {
+        let group: Group = toml::from_str(indoc! {r##"
+            colors = { gradient = ["#fde68a", "#b91c1c"] }
+            labels = ["P-low", "P-medium", "P-high", "P-critical"]
+        "##})
+        .unwrap();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+        let names: Vec<&str> = labels.iter().map(|label| label.name().get()).collect();
+
+        assert_eq!(names, vec!["P-low", "P-medium", "P-high", "P-critical"]);
+        assert_eq!(*labels[0].color(), Color::from_hex(0xfde68a));
+        assert_eq!(*labels[3].color(), Color::from_hex(0xb91c1c));
+    }
+
+    #[test]
+    fn expand_keeps_label_order_of_tailwind_with_order() {
+        let group: Group = toml::from_str(indoc! {r#"
+            colors = { tailwind = "red", shades = [300, 500, 700], order = "dark-to-light" }
+            labels = ["P-critical", "P-high", "P-low"]
+        "#})
+        .unwrap();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+
+        assert_eq!(labels[0].name().get(), "P-critical");
+        assert_eq!(*labels[0].color(), Color::from_hex(0xb91c1c));
+        assert_eq!(labels[2].name().get(), "P-low");
+        assert_eq!(*labels[2].color(), Color::from_hex(0xfca5a5));
+    }
+
+    #[test]
+    fn expand_exclusive() {
+        let group = Group::builder()
+            .prefix(Prefix::new("C/"))
+            .colors(Colors::Tailwind(Tailwind::Red.into()))
+            .labels(vec![LabelVariant::Name("bug".into())])
+            .exclusive(true)
+            .build();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+
+        assert_eq!(labels[0].name().get(), "C/bug");
+        assert!(labels[0].exclusive());
+    }
+
+    #[test]
+    fn expand_with_priority() {
+        let group: Group = toml::from_str(indoc! {r#"
+            colors = { tailwind = "red" }
+            labels = ["bug", { name = "security", priority = 1 }]
+        "#})
+        .unwrap();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+
+        assert_eq!(labels[0].priority(), None);
+        assert_eq!(labels[1].priority(), Some(1));
+    }
+
+    #[test]
+    fn expand_with_target() {
+        let group: Group = toml::from_str(indoc! {r#"
+            colors = { tailwind = "red" }
+            labels = ["lgtm"]
+            target = "prs"
+        "#})
+        .unwrap();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+
+        assert_eq!(labels[0].target(), Target::Prs);
+    }
+
+    #[test]
+    fn expand_for_prow() {
+        let group: Group = toml::from_str(indoc! {r#"
+            colors = { tailwind = "red" }
+            labels = [{ name = "needs-rebase", delete_after = "2026-01-01T00:00:00Z" }]
+            added_by = "prow"
+            repos = ["jdno/labelflair"]
+        "#})
+        .unwrap();
+
+        let labels = group.expand(&BTreeMap::new()).unwrap();
+
+        assert_eq!(
+            labels[0].delete_after(),
+            &Some(Timestamp::new("2026-01-01T00:00:00Z"))
+        );
+        assert_eq!(labels[0].added_by(), &Some("prow".to_string()));
+        assert_eq!(labels[0].repos(), &vec!["jdno/labelflair".to_string()]);
+    }
+
+    #[test]
+    fn expand_with_palette() {
+        let group = Group::builder()
+            .prefix(None)
+            .colors(Colors::Palette(PaletteRef::new("brand")))
+            .labels(vec![LabelVariant::Name("bug".into())])
+            .build();
+        let palettes = BTreeMap::from([(
+            PaletteName::new("brand"),
+            Palette::new(vec![Color::from_hex(0x3b82f6)]).unwrap(),
+        )]);
+
+        let labels = group.expand(&palettes).unwrap();
+
+        assert_eq!(*labels[0].color(), Color::from_hex(0x3b82f6));
+    }
+
+    #[test]
+    fn expand_with_unknown_palette() {
+        let group = Group::builder()
+            .prefix(None)
+            .colors(Colors::Palette(PaletteRef::new("brand")))
+            .labels(vec![LabelVariant::Name("bug".into())])
+            .build();
+
+        let error = group.expand(&BTreeMap::new()).unwrap_err();
+
+        assert!(matches!(error, Error::UnknownPalette { name } if name == "brand"));
+    }
+
+    #[test]
+    fn expand_with_missing_colors() {
+        let group = Group::builder()
+            .prefix(None)
+            .colors(Colors::Tailwind(
+                TailwindScale::builder()
+                    .color(Tailwind::Red)
+                    .shades(Some(Vec::new()))
+                    .build(),
+            ))
+            .labels(vec![LabelVariant::Name("bug".into())])
+            .build();
+
+        let error = group.expand(&BTreeMap::new()).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::MissingColors {
+                expected: 1,
+                actual: 0
+            }
+        ));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
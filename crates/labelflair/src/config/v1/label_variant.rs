@@ -6,17 +6,19 @@
 
 use std::fmt::{Display, Formatter};
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 
-use crate::label::{Description, LabelName};
+use crate::Error;
+use crate::label::{Description, LabelName, Timestamp};
 
 /// Different variations to represent a label in the configuration
 ///
 /// This enum can represent a label either as a simple name or with an associated description. To
 /// make it easy for users to write their configuration, deserialization is smart and can detect the
 /// variant automatically.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize)]
-#[serde(untagged)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+#[serde(try_from = "RawLabelVariant")]
 pub enum LabelVariant {
     /// A label with just a name
     Name(LabelName),
@@ -26,11 +28,47 @@ pub enum LabelVariant {
         /// The name of the label
         name: LabelName,
         /// The description for the label
-        #[serde(default)]
         description: Option<Description>,
         /// Optional aliases for the label
-        #[serde(default)]
         aliases: Vec<LabelName>,
+        /// An optional priority for the label
+        priority: Option<u32>,
+        /// An optional time after which the label can be deleted
+        delete_after: Option<Timestamp>,
+    },
+}
+
+/// The unchecked representation of a label in the configuration
+///
+/// Serde cannot report why a value did not match any variant of an untagged enum, so the label is
+/// first deserialized into this enum with plain strings. The values are then checked when it is
+/// converted into a [`LabelVariant`], which results in clear error messages.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RawLabelVariant {
+    /// A label with just a name
+    Name(#[schemars(with = "LabelName")] String),
+
+    /// A label with a name and a description
+    WithDescription {
+        /// The name of the label
+        #[schemars(with = "LabelName")]
+        name: String,
+        /// The description for the label
+        #[schemars(with = "Description")]
+        #[serde(default)]
+        description: Option<String>,
+        /// Optional aliases for the label
+        #[schemars(with = "Vec<LabelName>")]
+        #[serde(default)]
+        aliases: Vec<String>,
+        /// An optional priority for the label
+        #[serde(default)]
+        priority: Option<u32>,
+        /// An optional time after which the label can be deleted
+        #[schemars(with = "Option<Timestamp>")]
+        #[serde(default)]
+        delete_after: Option<String>,
     },
 }
 
@@ -58,6 +96,50 @@ impl LabelVariant {
             LabelVariant::WithDescription { aliases, .. } => Some(aliases),
         }
     }
+
+    /// Returns the optional priority of the label
+    pub fn priority(&self) -> Option<u32> {
+        match self {
+            LabelVariant::Name(_) => None,
+            LabelVariant::WithDescription { priority, .. } => *priority,
+        }
+    }
+
+    /// Returns the optional time after which the label can be deleted
+    pub fn delete_after(&self) -> Option<&Timestamp> {
+        match self {
+            LabelVariant::Name(_) => None,
+            LabelVariant::WithDescription { delete_after, .. } => delete_after.as_ref(),
+        }
+    }
+}
+
+impl TryFrom<RawLabelVariant> for LabelVariant {
+    type Error = Error;
+
+    fn try_from(raw: RawLabelVariant) -> Result<Self, Self::Error> {
+        let variant = match raw {
+            RawLabelVariant::Name(name) => LabelVariant::Name(LabelName::try_new(name)?),
+            RawLabelVariant::WithDescription {
+                name,
+                description,
+                aliases,
+                priority,
+                delete_after,
+            } => LabelVariant::WithDescription {
+                name: LabelName::try_new(name)?,
+                description: description.map(Description::try_new).transpose()?,
+                aliases: aliases
+                    .into_iter()
+                    .map(LabelName::try_new)
+                    .collect::<Result<_, _>>()?,
+                priority,
+                delete_after: delete_after.map(Timestamp::try_new).transpose()?,
+            },
+        };
+
+        Ok(variant)
+    }
 }
 
 impl Display for LabelVariant {
@@ -96,6 +178,8 @@ mod tests {
                     name: "enhancement".into(),
                     description: Some("A new feature or improvement".into()),
                     aliases: Vec::new(),
+                    priority: None,
+                    delete_after: None,
                 },
             ],
         };
@@ -103,6 +187,33 @@ mod tests {
         assert_eq!(container, expected);
     }
 
+    #[test]
+    fn trait_deserialize_with_long_description() {
+        let toml = format!(
+            "labels = [{{ name = \"bug\", description = \"{}\" }}]",
+            "a".repeat(101)
+        );
+
+        let error = toml::from_str::<Container>(&toml).unwrap_err();
+
+        assert_eq!(
+            error.message(),
+            "description is 101 characters long, but GitHub allows at most 100 characters"
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_timestamp() {
+        let toml = r#"labels = [{ name = "bug", delete_after = "tomorrow" }]"#;
+
+        let error = toml::from_str::<Container>(toml).unwrap_err();
+
+        assert_eq!(
+            error.message(),
+            "timestamp `tomorrow` is not an RFC 3339 date and time with an offset, e.g. `2026-01-01T00:00:00Z`"
+        );
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
@@ -0,0 +1,150 @@
+//! Minimum contrast of labels
+//!
+//! This module defines the [`MinContrast`] type, which sets the contrast ratio that the text of
+//! every label must reach.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer};
+
+/// The contrast ratio below which labels are reported as warnings if no minimum is configured
+///
+/// This is the minimum that the Web Content Accessibility Guidelines require for large text.
+pub const DEFAULT_MIN_CONTRAST: f64 = 3.0;
+
+/// The minimum contrast ratio of the text on a label
+///
+/// Contrast ratios range from `1.0` to `21.0`. When a minimum is configured, labels whose text has a
+/// lower contrast with their color make the configuration invalid.
+#[derive(Copy, Clone, Debug)]
+pub struct MinContrast(f64);
+
+impl MinContrast {
+    /// Create a new minimum contrast ratio
+    ///
+    /// Returns `None` if the ratio is not between `1.0` and `21.0`.
+    pub fn new(ratio: f64) -> Option<Self> {
+        (1.0..=21.0).contains(&ratio).then_some(Self(ratio))
+    }
+
+    /// Returns the contrast ratio
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Display for MinContrast {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq for MinContrast {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MinContrast {}
+
+impl PartialOrd for MinContrast {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinContrast {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for MinContrast {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl JsonSchema for MinContrast {
+    fn schema_name() -> Cow<'static, str> {
+        "MinContrast".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "description": "The minimum contrast ratio between the text and the color of every label",
+            "minimum": 1.0,
+            "maximum": 21.0,
+            "examples": [3.0, 4.5],
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for MinContrast {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ratio = f64::deserialize(deserializer)?;
+
+        Self::new(ratio).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "minimum contrast `{ratio}` is not between 1 and 21"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(MinContrast::new(4.5).map(|ratio| ratio.get()), Some(4.5));
+        assert_eq!(MinContrast::new(0.5), None);
+        assert_eq!(MinContrast::new(22.0), None);
+        assert_eq!(MinContrast::new(f64::NAN), None);
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let ratio: MinContrast = serde_yaml_ng::from_str("4.5").unwrap();
+
+        assert_eq!(ratio, MinContrast::new(4.5).unwrap());
+    }
+
+    #[test]
+    fn trait_deserialize_out_of_range() {
+        let error = serde_yaml_ng::from_str::<MinContrast>("25").unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("minimum contrast `25` is not between 1 and 21")
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MinContrast>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MinContrast>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<MinContrast>();
+    }
+}
@@ -0,0 +1,633 @@
+//! Semantic validation of the configuration
+//!
+//! A configuration can be syntactically correct and still describe a set of labels that cannot be
+//! created on GitHub, for example when two labels end up with the same name. This module implements
+//! a validation pass over [`ConfigV1`] that reports such problems as [`Diagnostic`]s.
+
+use std::collections::hash_map::Entry as MapEntry;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::color::Color;
+use crate::colors::{Colors, Tailwind};
+use crate::diagnostic::Diagnostic;
+use crate::label::{Label, LabelName};
+
+use super::{ConfigV1, DEFAULT_MIN_CONTRAST, Group};
+
+/// A label in the configuration with its final name
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct Definition {
+    /// The final name of the label, including the prefix of its group
+    name: LabelName,
+
+    /// The aliases of the label
+    aliases: Vec<LabelName>,
+
+    /// The index of the group that defines the label, if any
+    group: Option<usize>,
+}
+
+impl ConfigV1 {
+    /// Validate the configuration
+    ///
+    /// This method checks the configuration for problems that would result in an invalid set of
+    /// labels, and returns a list of diagnostics. The following problems are reported as errors:
+    ///
+    /// - Two labels have the same name, compared case-insensitively as GitHub does
+    /// - An alias collides with the name of another label
+    /// - An alias is used by more than one label
+    /// - A label lists itself as an alias
+    /// - A name or description is not accepted by GitHub, for example because the prefix of a
+    ///   group makes a name too long
+    /// - An exclusive group has a prefix that does not end with a `/`, which is needed to scope
+    ///   the labels
+    /// - A group uses a palette that is not defined, or its color generator does not return a
+    ///   color for every label
+    /// - A group has an empty `added_by`, or lists a repository that is not in the format
+    ///   `owner/name` or that it lists more than once
+    ///
+    /// Groups without any labels and groups that use the deprecated Tailwind color `natural` are
+    /// reported as warnings. Labels whose text has a lower contrast ratio than the configured
+    /// `min_contrast` are reported as errors, or as warnings below [`DEFAULT_MIN_CONTRAST`] if no
+    /// minimum has been configured.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let definitions = self.definitions();
+        let mut diagnostics = self.constraints(&definitions);
+
+        for (index, group) in self.groups.iter().enumerate() {
+            let scoped = group
+                .prefix()
+                .as_ref()
+                .is_some_and(|prefix| prefix.get().ends_with('/'));
+
+            if group.exclusive() && !scoped {
+                diagnostics.push(Diagnostic::error(format!(
+                    "{} is exclusive, but its prefix does not end with `/`",
+                    describe_group(index, group)
+                )));
+            }
+
+            if group
+                .added_by()
+                .as_ref()
+                .is_some_and(|added_by| added_by.trim().is_empty())
+            {
+                diagnostics.push(Diagnostic::error(format!(
+                    "{} has an empty `added_by`",
+                    describe_group(index, group)
+                )));
+            }
+
+            let mut repos = BTreeSet::new();
+
+            for repo in group.repos() {
+                let valid = repo.split_once('/').is_some_and(|(owner, name)| {
+                    !owner.is_empty() && !name.is_empty() && !name.contains('/')
+                });
+
+                if !valid {
+                    diagnostics.push(Diagnostic::error(format!(
+                        "{} lists the repository `{repo}`, which is not in the format `owner/name`",
+                        describe_group(index, group)
+                    )));
+                } else if !repos.insert(repo.to_lowercase()) {
+                    diagnostics.push(Diagnostic::error(format!(
+                        "{} lists the repository `{repo}` more than once",
+                        describe_group(index, group)
+                    )));
+                }
+            }
+
+            if let Err(error) = group.expand(&self.palettes) {
+                diagnostics.push(Diagnostic::error(format!(
+                    "{}: {error}",
+                    describe_group(index, group)
+                )));
+            }
+
+            if group.labels().is_empty() {
+                diagnostics.push(Diagnostic::warning(format!(
+                    "{} does not define any labels",
+                    describe_group(index, group)
+                )));
+            }
+
+            if let Colors::Tailwind(scale) = group.colors() {
+                #[allow(deprecated)]
+                let natural = scale.color() == Tailwind::Natural;
+
+                if natural {
+                    diagnostics.push(Diagnostic::warning(format!(
+                        "{} uses the Tailwind color `natural`, which is deprecated in favor of `neutral`",
+                        describe_group(index, group)
+                    )));
+                }
+            }
+        }
+
+        let mut names: HashMap<String, &Definition> = HashMap::new();
+
+        for definition in &definitions {
+            match names.entry(normalize(&definition.name)) {
+                MapEntry::Vacant(entry) => {
+                    entry.insert(definition);
+                }
+                MapEntry::Occupied(entry) => {
+                    diagnostics.push(self.duplicate(entry.get(), definition));
+                }
+            }
+        }
+
+        let mut aliases: HashMap<String, &Definition> = HashMap::new();
+
+        for definition in &definitions {
+            for alias in &definition.aliases {
+                let key = normalize(alias);
+
+                if let Some(other) = names.get(&key) {
+                    if normalize(&other.name) != normalize(&definition.name) {
+                        diagnostics.push(Diagnostic::error(format!(
+                            "alias `{alias}` of label `{}` collides with the label `{}`",
+                            definition.name, other.name
+                        )));
+                    } else {
+                        diagnostics.push(Diagnostic::error(format!(
+                            "label `{}` lists itself as an alias",
+                            definition.name
+                        )));
+                    }
+                }
+
+                match aliases.entry(key) {
+                    MapEntry::Vacant(entry) => {
+                        entry.insert(definition);
+                    }
+                    MapEntry::Occupied(entry) if entry.get().name != definition.name => {
+                        diagnostics.push(Diagnostic::error(format!(
+                            "alias `{alias}` is used by both `{}` and `{}`",
+                            entry.get().name,
+                            definition.name
+                        )));
+                    }
+                    MapEntry::Occupied(_) => {}
+                }
+            }
+        }
+
+        diagnostics.extend(self.contrast());
+
+        diagnostics
+    }
+
+    /// Check the contrast of the text that GitHub draws on every label
+    fn contrast(&self) -> Vec<Diagnostic> {
+        let (minimum, diagnostic): (f64, fn(String) -> Diagnostic) = match self.min_contrast {
+            Some(minimum) => (minimum.get(), Diagnostic::error),
+            None => (DEFAULT_MIN_CONTRAST, Diagnostic::warning),
+        };
+
+        let labels = self.labels.iter().cloned().chain(
+            self.groups
+                .iter()
+                .filter_map(|group| group.expand(&self.palettes).ok())
+                .flatten(),
+        );
+
+        labels
+            .filter_map(|label: Label| {
+                let contrast = label.contrast();
+
+                if contrast.text() >= minimum {
+                    return None;
+                }
+
+                let text = if label.color().text_color() == Color::WHITE {
+                    "white"
+                } else {
+                    "black"
+                };
+
+                Some(diagnostic(format!(
+                    "label `{}` has a contrast ratio of {:.2} with its {text} text, which is below the minimum of {minimum}",
+                    label.name(),
+                    contrast.text()
+                )))
+            })
+            .collect()
+    }
+
+    /// Collect the final names and aliases of all labels in the configuration
+    fn definitions(&self) -> Vec<Definition> {
+        let labels = self.labels.iter().map(|label| Definition {
+            name: label.name().clone(),
+            aliases: label.aliases().clone(),
+            group: None,
+        });
+
+        let groups = self.groups.iter().enumerate().flat_map(|(index, group)| {
+            let prefix = group.prefix().clone().unwrap_or("".into());
+
+            group.labels().iter().map(move |label| Definition {
+                name: format!("{prefix}{label}").into(),
+                aliases: label.aliases().cloned().unwrap_or_default(),
+                group: Some(index),
+            })
+        });
+
+        labels.chain(groups).collect()
+    }
+
+    /// Check the names and descriptions against the limits of GitHub
+    fn constraints(&self, definitions: &[Definition]) -> Vec<Diagnostic> {
+        let names = definitions
+            .iter()
+            .filter_map(|definition| definition.name.validate().err());
+
+        let label_fields = self.labels.iter().filter_map(|label| {
+            label
+                .description()
+                .as_ref()
+                .and_then(|description| description.validate().err())
+                .map(|error| (label.name().to_string(), error))
+        });
+
+        let group_fields = self.groups.iter().flat_map(|group| {
+            let prefix = group.prefix().clone().unwrap_or("".into());
+
+            group.labels().iter().filter_map(move |label| {
+                label
+                    .description()
+                    .and_then(|description| description.validate().err())
+                    .map(|error| (format!("{prefix}{label}"), error))
+            })
+        });
+
+        names
+            .map(|error| Diagnostic::error(error.to_string()))
+            .chain(
+                label_fields
+                    .chain(group_fields)
+                    .map(|(name, error)| Diagnostic::error(format!("label `{name}`: {error}"))),
+            )
+            .collect()
+    }
+
+    /// Create the diagnostic for a label that has been defined twice
+    fn duplicate(&self, first: &Definition, second: &Definition) -> Diagnostic {
+        let message = match (first.group, second.group) {
+            (Some(a), Some(b)) if a == b => format!(
+                "label `{}` is defined more than once in {}",
+                second.name,
+                describe_group(a, &self.groups[a])
+            ),
+            _ if first.name == second.name => {
+                format!("label `{}` is defined more than once", second.name)
+            }
+            _ => format!(
+                "labels `{}` and `{}` have the same name, because GitHub compares label names case-insensitively",
+                first.name, second.name
+            ),
+        };
+
+        Diagnostic::error(message)
+    }
+}
+
+/// Describe a group for use in a diagnostic
+fn describe_group(index: usize, group: &Group) -> String {
+    match group.prefix() {
+        Some(prefix) => format!("group with prefix `{prefix}`"),
+        None => format!("group #{}", index + 1),
+    }
+}
+
+/// Normalize a label name for case-insensitive comparisons
+fn normalize(name: &LabelName) -> String {
+    name.to_string().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+    use crate::diagnostic::Severity;
+    use crate::label::Label;
+
+    use super::*;
+
+    fn validate(toml: &str) -> Vec<Diagnostic> {
+        let config: ConfigV1 = toml.parse().unwrap();
+
+        config.validate()
+    }
+
+    #[test]
+    fn validate_valid_config() {
+        let diagnostics = validate(indoc! {r##"
+            [[label]]
+            name = "good first issue"
+            color = "#4ade80"
+            aliases = ["help wanted"]
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug", { name = "feature", aliases = ["enhancement"] }]
+        "##});
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn validate_duplicate_names_across_groups() {
+        let diagnostics = validate(indoc! {r##"
+            [[label]]
+            name = "C-Bug"
+            color = "#4ade80"
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "labels `C-Bug` and `C-bug` have the same name, because GitHub compares label names case-insensitively"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_names_in_group() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug", { name = "bug", description = "A bug" }]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "label `C-bug` is defined more than once in group with prefix `C-`"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_alias_collisions() {
+        let diagnostics = validate(indoc! {r##"
+            [[label]]
+            name = "bug"
+            color = "#4ade80"
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = [{ name = "defect", aliases = ["Bug"] }]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "alias `Bug` of label `C-defect` collides with the label `bug`"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_shared_aliases() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = [
+                { name = "defect", aliases = ["bug"] },
+                { name = "problem", aliases = ["bug"] },
+            ]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "alias `bug` is used by both `defect` and `problem`"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_alias_cycles() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = [
+                { name = "a", aliases = ["b"] },
+                { name = "b", aliases = ["a"] },
+            ]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::error("alias `b` of label `a` collides with the label `b`"),
+                Diagnostic::error("alias `a` of label `b` collides with the label `a`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_self_alias() {
+        let diagnostics = validate(indoc! {r##"
+            [[label]]
+            name = "bug"
+            color = "#4ade80"
+            aliases = ["BUG"]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error("label `bug` lists itself as an alias")]
+        );
+    }
+
+    #[test]
+    fn validate_prefixed_name_length() {
+        let diagnostics = validate(&format!(
+            "[[group]]\nprefix = \"area/\"\ncolors = {{ tailwind = \"red\" }}\nlabels = [\"{}\"]\n",
+            "a".repeat(48)
+        ));
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(format!(
+                "label name `area/{}` is 53 characters long, but GitHub allows at most 50 characters",
+                "a".repeat(48)
+            ))]
+        );
+    }
+
+    #[test]
+    fn validate_unchecked_fields() {
+        let config = ConfigV1::builder()
+            .labels(vec![
+                Label::builder()
+                    .name("bug")
+                    .color(Color::from_hex(0xff0000))
+                    .description(Some("a".repeat(101).into()))
+                    .build(),
+            ])
+            .groups(Vec::new())
+            .build();
+
+        assert_eq!(
+            config.validate(),
+            vec![Diagnostic::error(
+                "label `bug`: description is 101 characters long, but GitHub allows at most 100 characters"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_exclusive_groups() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            prefix = "C/"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+            exclusive = true
+
+            [[group]]
+            prefix = "P-"
+            colors = { tailwind = "blue" }
+            labels = ["high"]
+            exclusive = true
+
+            [[group]]
+            colors = { tailwind = "green" }
+            labels = ["docs"]
+            exclusive = true
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::error(
+                    "group with prefix `P-` is exclusive, but its prefix does not end with `/`"
+                ),
+                Diagnostic::error("group #3 is exclusive, but its prefix does not end with `/`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_prow_fields() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+            added_by = " "
+            repos = ["jdno/labelflair", "labelflair", "jdno/LabelFlair"]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::error("group with prefix `C-` has an empty `added_by`"),
+                Diagnostic::error(
+                    "group with prefix `C-` lists the repository `labelflair`, which is not in the format `owner/name`"
+                ),
+                Diagnostic::error(
+                    "group with prefix `C-` lists the repository `jdno/LabelFlair` more than once"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_low_contrast() {
+        let diagnostics = validate(indoc! {r##"
+            [[label]]
+            name = "magenta"
+            color = "#ff32ff"
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::warning(
+                "label `magenta` has a contrast ratio of 2.94 with its white text, which is below the minimum of 3"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_min_contrast() {
+        let diagnostics = validate(indoc! {r##"
+            min_contrast = 4.5
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "label `C-bug` has a contrast ratio of 3.76 with its white text, which is below the minimum of 4.5"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_empty_groups() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            colors = { tailwind = "red" }
+            labels = []
+        "##});
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity(), Severity::Warning);
+        assert_eq!(
+            diagnostics[0].message(),
+            "group #1 does not define any labels"
+        );
+    }
+
+    #[test]
+    fn validate_deprecated_tailwind_color() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "natural" }
+            labels = [{ name = "bug" }]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::warning(
+                "group with prefix `C-` uses the Tailwind color `natural`, which is deprecated in favor of `neutral`"
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_unknown_palette() {
+        let diagnostics = validate(indoc! {r##"
+            [[group]]
+            prefix = "C-"
+            colors = { palette = "brand-blue" }
+            labels = ["bug"]
+        "##});
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                "group with prefix `C-`: palette `brand-blue` is not defined"
+            )]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Diagnostics for problems in a configuration
+//!
+//! This module defines the [`Diagnostic`] type, which describes a problem that has been found in a
+//! configuration. Each diagnostic has a [`Severity`] that decides whether the configuration can
+//! still be used to generate labels.
+
+use std::fmt::{Display, Formatter};
+
+use getset::{CopyGetters, Getters};
+
+/// A problem that has been found in a configuration
+///
+/// Diagnostics with the severity [`Severity::Error`] make a configuration invalid, while
+/// diagnostics with the severity [`Severity::Warning`] point out potential mistakes.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, CopyGetters, Getters)]
+pub struct Diagnostic {
+    /// The severity of the problem
+    #[getset(get_copy = "pub")]
+    severity: Severity,
+
+    /// A human-readable description of the problem
+    #[getset(get = "pub")]
+    message: String,
+}
+
+/// The severity of a diagnostic
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Severity {
+    /// A potential mistake that does not prevent labels from being generated
+    Warning,
+
+    /// A problem that prevents labels from being generated
+    Error,
+}
+
+impl Diagnostic {
+    /// Create a new diagnostic with the severity [`Severity::Error`]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new diagnostic with the severity [`Severity::Warning`]
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+
+    /// Returns `true` if the diagnostic is an error
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let severity = match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+
+        write!(f, "{severity}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_error() {
+        assert!(Diagnostic::error("duplicate label").is_error());
+        assert!(!Diagnostic::warning("empty group").is_error());
+    }
+
+    #[test]
+    fn trait_display() {
+        let diagnostic = Diagnostic::warning("group #1 does not define any labels");
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "warning: group #1 does not define any labels"
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Diagnostic>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Diagnostic>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Diagnostic>();
+    }
+}
@@ -0,0 +1,320 @@
+//! Documentation for the labels
+//!
+//! Contributors need to know what the labels in a repository mean. This module renders the labels
+//! in the configuration as Markdown, with a section for every group and a table that shows each
+//! label with a color swatch, its description, and its aliases. The documentation can be written
+//! to its own page with [`page`], or embedded into an existing document with [`embed`].
+
+use std::fmt::Write as _;
+
+use crate::config::v1::{ConfigV1, Group};
+use crate::label::Label;
+use crate::{Error, Labelflair};
+
+/// The comment that marks the start of the documentation in a document
+pub const START_MARKER: &str = "<!-- labelflair:start -->";
+
+/// The comment that marks the end of the documentation in a document
+pub const END_MARKER: &str = "<!-- labelflair:end -->";
+
+/// The base URL for the badges that show the color of a label
+const BADGE_URL: &str = "https://img.shields.io/badge";
+
+/// Render the documentation for the labels in the configuration
+///
+/// The documentation has a section for every group, followed by a section for the individual
+/// labels. The configuration is validated first, and an [`Error::Invalid`] is returned if the
+/// validation finds any errors.
+pub fn render(config: &ConfigV1) -> Result<String, Error> {
+    // Validate the configuration the same way as when the labels are generated
+    Labelflair::generate(config)?;
+
+    let mut sections: Vec<String> = config
+        .groups()
+        .iter()
+        .enumerate()
+        .map(|(index, group)| {
+            Ok(section(
+                &heading(index, group),
+                &group.expand(config.palettes())?,
+            ))
+        })
+        .collect::<Result<_, Error>>()?;
+
+    if !config.labels().is_empty() {
+        sections.push(section("Other labels", config.labels()));
+    }
+
+    Ok(sections.join("\n"))
+}
+
+/// Render the documentation as a standalone Markdown page
+pub fn page(config: &ConfigV1) -> Result<String, Error> {
+    let mut page = String::new();
+
+    page.push_str("# Labels\n\n");
+    page.push_str(
+        "This page lists the labels that are used to organize issues and pull requests.\n",
+    );
+    page.push_str("It is generated by [Labelflair](https://github.com/jdno/labelflair).\n\n");
+    page.push_str(&render(config)?);
+
+    Ok(page)
+}
+
+/// Embed the documentation into an existing document
+///
+/// The documentation replaces everything between [`START_MARKER`] and [`END_MARKER`] in the
+/// document, while the markers and the rest of the document are kept. An
+/// [`Error::MissingMarkers`] is returned if the document does not contain both markers in order.
+pub fn embed(document: &str, config: &ConfigV1) -> Result<String, Error> {
+    let missing = || Error::MissingMarkers {
+        start: START_MARKER,
+        end: END_MARKER,
+    };
+
+    let start = document.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = document[start..]
+        .find(END_MARKER)
+        .map(|index| start + index)
+        .ok_or_else(missing)?;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &document[..start],
+        render(config)?,
+        &document[end..]
+    ))
+}
+
+/// Returns the heading for a group
+///
+/// Groups are named after their `name`, then their prefix, and finally their position in the
+/// configuration. The prefix is formatted as code, since it is not a name.
+pub fn heading(index: usize, group: &Group) -> String {
+    match (group.name(), group.prefix()) {
+        (Some(name), _) => name.to_string(),
+        (None, Some(prefix)) => format!("`{prefix}`"),
+        (None, None) => format!("Group {}", index + 1),
+    }
+}
+
+/// Render a section with a heading and a table of labels
+fn section(heading: &str, labels: &[Label]) -> String {
+    let mut section = String::new();
+
+    let _ = writeln!(section, "## {heading}\n");
+    let _ = writeln!(section, "| Label | Description | Aliases |");
+    let _ = writeln!(section, "| ----- | ----------- | ------- |");
+
+    for label in labels {
+        let aliases = label
+            .aliases()
+            .iter()
+            .map(|alias| format!("`{alias}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let _ = writeln!(
+            section,
+            "| {} | {} | {} |",
+            badge(label),
+            label
+                .description()
+                .as_ref()
+                .map(|description| escape(description.get()))
+                .unwrap_or_default(),
+            escape(&aliases),
+        );
+    }
+
+    section
+}
+
+/// Render a badge that shows the name of the label in its color
+fn badge(label: &Label) -> String {
+    let name = label.name().get();
+    let color = label.color().to_string();
+
+    format!(
+        "![{}]({BADGE_URL}/{}-{})",
+        escape(name).replace('[', "\\[").replace(']', "\\]"),
+        badge_text(name),
+        color.trim_start_matches('#')
+    )
+}
+
+/// Encode text for the path of a badge
+///
+/// Dashes and underscores separate the parts of a badge, so they are escaped by doubling them.
+/// Characters that are not allowed in a URL are percent-encoded.
+fn badge_text(text: &str) -> String {
+    let mut encoded = String::new();
+
+    for c in text.chars() {
+        match c {
+            '-' => encoded.push_str("--"),
+            '_' => encoded.push_str("__"),
+            c if c.is_ascii_alphanumeric() || c == '.' || c == '~' => encoded.push(c),
+            c => {
+                let mut buffer = [0; 4];
+
+                for byte in c.encode_utf8(&mut buffer).bytes() {
+                    let _ = write!(encoded, "%{byte:02X}");
+                }
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Escape text for a cell in a Markdown table
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+    use crate::colors::{Colors, Fixed};
+    use crate::config::v1::{GroupName, Prefix};
+
+    use super::*;
+
+    fn config() -> ConfigV1 {
+        indoc! {r##"
+            [[label]]
+            name = "good first issue"
+            color = "#4ade80"
+            description = "Good for newcomers"
+
+            [[group]]
+            name = "Categories"
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = [
+                { name = "bug", description = "Something isn't working", aliases = ["bug"] },
+                "feature",
+            ]
+
+            [[group]]
+            prefix = "T-"
+            colors = { fixed = "#0ea5e9" }
+            labels = [{ name = "cli", description = "The command-line | interface" }]
+        "##}
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn render_groups_and_labels() {
+        let docs = render(&config()).unwrap();
+
+        assert_eq!(
+            docs,
+            indoc! {r#"
+                ## Categories
+
+                | Label | Description | Aliases |
+                | ----- | ----------- | ------- |
+                | ![C-feature](https://img.shields.io/badge/C--feature-fca5a5) |  |  |
+                | ![C-bug](https://img.shields.io/badge/C--bug-b91c1c) | Something isn't working | `bug` |
+
+                ## `T-`
+
+                | Label | Description | Aliases |
+                | ----- | ----------- | ------- |
+                | ![T-cli](https://img.shields.io/badge/T--cli-0ea5e9) | The command-line \| interface |  |
+
+                ## Other labels
+
+                | Label | Description | Aliases |
+                | ----- | ----------- | ------- |
+                | ![good first issue](https://img.shields.io/badge/good%20first%20issue-4ade80) | Good for newcomers |  |
+            "#}
+        );
+    }
+
+    #[test]
+    fn render_with_invalid_config() {
+        let config: ConfigV1 = indoc! {r##"
+            [[label]]
+            name = "bug"
+            color = "#4ade80"
+
+            [[label]]
+            name = "bug"
+            color = "#4ade80"
+        "##}
+        .parse()
+        .unwrap();
+
+        assert!(matches!(render(&config), Err(Error::Invalid(_))));
+    }
+
+    #[test]
+    fn heading_for_groups() {
+        let group = |name: Option<&str>, prefix: Option<&str>| {
+            Group::builder()
+                .name(name.map(GroupName::new))
+                .prefix(prefix.map(Prefix::new))
+                .colors(Colors::Fixed(Fixed::new(Color::WHITE)))
+                .labels(Vec::new())
+                .build()
+        };
+
+        assert_eq!(heading(0, &group(Some("Areas"), Some("A-"))), "Areas");
+        assert_eq!(heading(0, &group(None, Some("A-"))), "`A-`");
+        assert_eq!(heading(2, &group(None, None)), "Group 3");
+    }
+
+    #[test]
+    fn page_has_title() {
+        let page = page(&config()).unwrap();
+
+        assert!(page.starts_with("# Labels\n\n"));
+        assert!(page.ends_with(&render(&config()).unwrap()));
+    }
+
+    #[test]
+    fn embed_between_markers() {
+        let document = indoc! {"
+            # Contributing
+
+            <!-- labelflair:start -->
+            outdated
+            <!-- labelflair:end -->
+
+            ## License
+        "};
+
+        let embedded = embed(document, &config()).unwrap();
+
+        assert!(
+            embedded.starts_with("# Contributing\n\n<!-- labelflair:start -->\n\n## Categories")
+        );
+        assert!(embedded.ends_with("|\n\n<!-- labelflair:end -->\n\n## License\n"));
+        assert!(!embedded.contains("outdated"));
+        assert_eq!(embed(&embedded, &config()).unwrap(), embedded);
+    }
+
+    #[test]
+    fn embed_without_markers() {
+        let error = embed("# Contributing\n", &config()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the document does not contain the markers `<!-- labelflair:start -->` and `<!-- labelflair:end -->`"
+        );
+    }
+
+    #[test]
+    fn badge_text_escapes_separators() {
+        assert_eq!(badge_text("C-bug"), "C--bug");
+        assert_eq!(badge_text("good_first issue"), "good__first%20issue");
+        assert_eq!(badge_text("🐛"), "%F0%9F%90%9B");
+    }
+}
@@ -0,0 +1,161 @@
+//! Errors in Labelflair
+//!
+//! This module defines the [`Error`] type that is returned by fallible operations in Labelflair.
+
+use thiserror::Error;
+
+use crate::diagnostic::Diagnostic;
+
+/// Errors in Labelflair
+///
+/// This enum represents the errors that can occur when working with Labelflair. Each variant
+/// carries enough context for applications to report the error to their users.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The configuration could not be parsed
+    ///
+    /// The underlying TOML error contains the message and the location of the error in the
+    /// configuration, which can be used to show the offending part of the file.
+    #[error("failed to parse the configuration: {}", .0.message())]
+    Parse(#[from] toml::de::Error),
+
+    /// The configuration is invalid
+    ///
+    /// The configuration has been parsed successfully, but the validation found problems that
+    /// prevent labels from being generated. The diagnostics describe each problem.
+    #[error("the configuration is invalid: {}", join(.0))]
+    Invalid(Vec<Diagnostic>),
+
+    /// A label name is not accepted by GitHub
+    #[error("label name `{name}` {reason}")]
+    InvalidName {
+        /// The invalid name
+        name: String,
+        /// The reason why the name is invalid
+        reason: String,
+    },
+
+    /// A description is not accepted by GitHub
+    #[error("description {reason}")]
+    InvalidDescription {
+        /// The invalid description
+        description: String,
+        /// The reason why the description is invalid
+        reason: String,
+    },
+
+    /// A timestamp is not a valid RFC 3339 date and time
+    #[error("timestamp `{timestamp}` {reason}")]
+    InvalidTimestamp {
+        /// The invalid timestamp
+        timestamp: String,
+        /// The reason why the timestamp is invalid
+        reason: String,
+    },
+
+    /// A color is not accepted by GitHub
+    #[error("color `{color}` {reason}")]
+    InvalidColor {
+        /// The invalid color
+        color: String,
+        /// The reason why the color is invalid
+        reason: String,
+    },
+
+    /// A group uses a palette that is not defined in the configuration
+    #[error("palette `{name}` is not defined")]
+    UnknownPalette {
+        /// The name of the palette
+        name: String,
+    },
+
+    /// A color generator returned fewer colors than a group has labels
+    #[error("the color generator returned {actual} colors for {expected} labels")]
+    MissingColors {
+        /// The number of labels in the group
+        expected: usize,
+        /// The number of colors that the generator returned
+        actual: usize,
+    },
+
+    /// The output format is not known
+    #[error("unknown output format `{format}`, expected one of {expected}")]
+    UnknownFormat {
+        /// The name of the unknown format
+        format: String,
+        /// The names of the known formats
+        expected: String,
+    },
+
+    /// The labels could not be written in the output format
+    #[error("failed to write the labels: {0}")]
+    Output(String),
+
+    /// The document does not contain the markers for the documentation
+    #[error("the document does not contain the markers `{start}` and `{end}`")]
+    MissingMarkers {
+        /// The marker at the start of the documentation
+        start: &'static str,
+        /// The marker at the end of the documentation
+        end: &'static str,
+    },
+}
+
+/// Join the messages of the diagnostics into a single line
+fn join(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message().as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::v1::ConfigV1;
+
+    use super::*;
+
+    #[test]
+    fn trait_display() {
+        let error = "[[group]".parse::<ConfigV1>().unwrap_err();
+
+        assert!(matches!(error, Error::Parse(_)));
+        assert!(
+            error
+                .to_string()
+                .starts_with("failed to parse the configuration: ")
+        );
+    }
+
+    #[test]
+    fn trait_display_invalid() {
+        let error = Error::Invalid(vec![
+            Diagnostic::error("label `bug` is defined more than once"),
+            Diagnostic::error("label `bug` lists itself as an alias"),
+        ]);
+
+        assert_eq!(
+            error.to_string(),
+            "the configuration is invalid: label `bug` is defined more than once; label `bug` lists itself as an alias"
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Error>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Error>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Error>();
+    }
+}
@@ -3,34 +3,284 @@
 //! This module defines the [`Label`] struct, which represents a label for GitHub Issues, and types
 //! for its fields.
 
-use getset::Getters;
-use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use getset::{CopyGetters, Getters};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize};
 use typed_builder::TypedBuilder;
-use typed_fields::name;
 
-name!(
-    /// The name of a label
-    LabelName
-);
+use crate::Error;
 
-name!(
-    /// The color of a label
-    ///
-    /// The `Color` type represents a color in hex format.
-    Color
-);
+pub use crate::color::{Color, Contrast};
+
+/// The maximum number of characters that GitHub allows in a label name
+pub const MAX_NAME_LENGTH: usize = 50;
+
+/// The maximum number of characters that GitHub allows in a label description
+pub const MAX_DESCRIPTION_LENGTH: usize = 100;
+
+/// The name of a label
+///
+/// GitHub requires label names to be non-empty and at most [`MAX_NAME_LENGTH`] characters long.
+/// Names are checked when they are deserialized or created with [`LabelName::try_new`], while
+/// [`LabelName::new`] accepts any name and leaves the check to [`LabelName::validate`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct LabelName(String);
+
+/// The description of a label
+///
+/// The `Description` type represents an optional description for a label. GitHub requires
+/// descriptions to be at most [`MAX_DESCRIPTION_LENGTH`] characters long.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Description(String);
+
+/// A point in time after which a label can be deleted
+///
+/// Prow's `label_sync` deletes a label once the time in its `deleteAfter` field has passed. The
+/// timestamp must be an RFC 3339 date and time with an offset, e.g. `2026-01-01T00:00:00Z`.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+/// The kind of items that a label is meant for
+///
+/// GitHub shares labels between issues and pull requests, but tools like Prow's `label_sync` can
+/// document that a label should only be applied to one of them.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Default,
+    Deserialize,
+    JsonSchema,
+    Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Target {
+    /// The label is meant for issues
+    Issues,
+
+    /// The label is meant for pull requests
+    Prs,
+
+    /// The label is meant for both issues and pull requests
+    #[default]
+    Both,
+}
+
+impl LabelName {
+    /// Create a new label name without checking it
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Create a new label name and check that GitHub accepts it
+    pub fn try_new(name: impl Into<String>) -> Result<Self, Error> {
+        let name = Self(name.into());
+        name.validate()?;
+
+        Ok(name)
+    }
+
+    /// Returns the label name as a string slice
+    pub fn get(&self) -> &str {
+        &self.0
+    }
 
-name!(
-    /// The description of a label
+    /// Check that GitHub accepts the label name
+    pub fn validate(&self) -> Result<(), Error> {
+        let length = self.0.chars().count();
+
+        let reason = if self.0.trim().is_empty() {
+            "must not be empty".to_string()
+        } else if length > MAX_NAME_LENGTH {
+            format!(
+                "is {length} characters long, but GitHub allows at most {MAX_NAME_LENGTH} characters"
+            )
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::InvalidName {
+            name: self.0.clone(),
+            reason,
+        })
+    }
+}
+
+impl Description {
+    /// Create a new description without checking it
+    pub fn new(description: impl Into<String>) -> Self {
+        Self(description.into())
+    }
+
+    /// Create a new description and check that GitHub accepts it
+    pub fn try_new(description: impl Into<String>) -> Result<Self, Error> {
+        let description = Self(description.into());
+        description.validate()?;
+
+        Ok(description)
+    }
+
+    /// Returns the description as a string slice
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    /// Check that GitHub accepts the description
+    pub fn validate(&self) -> Result<(), Error> {
+        let length = self.0.chars().count();
+
+        if length <= MAX_DESCRIPTION_LENGTH {
+            return Ok(());
+        }
+
+        Err(Error::InvalidDescription {
+            description: self.0.clone(),
+            reason: format!(
+                "is {length} characters long, but GitHub allows at most {MAX_DESCRIPTION_LENGTH} characters"
+            ),
+        })
+    }
+}
+
+impl Timestamp {
+    /// Create a new timestamp without checking it
+    pub fn new(timestamp: impl Into<String>) -> Self {
+        Self(timestamp.into())
+    }
+
+    /// Create a new timestamp and check that it is a valid RFC 3339 date and time
+    pub fn try_new(timestamp: impl Into<String>) -> Result<Self, Error> {
+        let timestamp = Self(timestamp.into());
+        timestamp.validate()?;
+
+        Ok(timestamp)
+    }
+
+    /// Returns the timestamp as a string slice
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    /// Check that the timestamp is a valid RFC 3339 date and time with an offset
     ///
-    /// The `Description` type represents an optional description for a label.
-    Description
-);
+    /// TOML and RFC 3339 share the format of dates and times, so the timestamp is parsed with the
+    /// TOML parser. TOML also accepts dates and times without an offset, which are rejected.
+    pub fn validate(&self) -> Result<(), Error> {
+        let is_valid = self
+            .0
+            .parse::<toml::value::Datetime>()
+            .is_ok_and(|datetime| {
+                datetime.date.is_some() && datetime.time.is_some() && datetime.offset.is_some()
+            });
+
+        if is_valid {
+            return Ok(());
+        }
+
+        Err(Error::InvalidTimestamp {
+            timestamp: self.0.clone(),
+            reason: "is not an RFC 3339 date and time with an offset, e.g. `2026-01-01T00:00:00Z`"
+                .into(),
+        })
+    }
+}
+
+/// Implement conversions, formatting, and checked deserialization for a string newtype
+macro_rules! string_newtype {
+    ($name:ident) => {
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+
+                Self::try_new(value).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+string_newtype!(LabelName);
+string_newtype!(Description);
+string_newtype!(Timestamp);
+
+impl JsonSchema for LabelName {
+    fn schema_name() -> Cow<'static, str> {
+        "LabelName".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The name of a label, which GitHub limits to 50 characters",
+            "minLength": 1,
+            "maxLength": MAX_NAME_LENGTH,
+        })
+    }
+}
+
+impl JsonSchema for Description {
+    fn schema_name() -> Cow<'static, str> {
+        "Description".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "The description of a label, which GitHub limits to 100 characters",
+            "maxLength": MAX_DESCRIPTION_LENGTH,
+        })
+    }
+}
+
+impl JsonSchema for Timestamp {
+    fn schema_name() -> Cow<'static, str> {
+        "Timestamp".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "An RFC 3339 date and time with an offset, e.g. 2026-01-01T00:00:00Z",
+            "format": "date-time",
+        })
+    }
+}
 
 /// A label for GitHub Issues
 ///
 /// Labels for GitHub Issues are used to categorize and organize issues in a repository. They have a
-/// unique name and a color represented in hex format.
+/// unique name and a color.
 #[derive(
     Clone,
     Eq,
@@ -39,8 +289,10 @@ name!(
     PartialOrd,
     Hash,
     Debug,
+    CopyGetters,
     Getters,
     Deserialize,
+    JsonSchema,
     Serialize,
     TypedBuilder,
 )]
@@ -58,6 +310,7 @@ pub struct Label {
     /// An optional description for the label
     #[builder(default, setter(into))]
     #[getset(get = "pub")]
+    #[schemars(with = "Description")]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     description: Option<Description>,
 
@@ -66,6 +319,101 @@ pub struct Label {
     #[getset(get = "pub")]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     aliases: Vec<LabelName>,
+
+    /// An optional priority for the label
+    ///
+    /// GitLab lists prioritized labels before other labels, starting with the lowest priority.
+    /// Other platforms ignore the priority.
+    #[builder(default, setter(into))]
+    #[getset(get_copy = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<u32>,
+
+    /// An optional time after which the label can be deleted
+    ///
+    /// Prow's `label_sync` deletes the label once this time has passed. Other platforms ignore it.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[schemars(with = "Timestamp")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delete_after: Option<Timestamp>,
+
+    /// Whether the label belongs to an exclusive group
+    ///
+    /// Only platforms with scoped labels, like Gitea and Forgejo, support exclusive labels. The flag
+    /// is set by groups and is not part of the serialized label.
+    #[builder(default)]
+    #[getset(get_copy = "pub")]
+    #[schemars(skip)]
+    #[serde(skip)]
+    exclusive: bool,
+
+    /// The kind of items that the label is meant for
+    ///
+    /// The target is set by groups and is not part of the serialized label.
+    #[builder(default)]
+    #[getset(get_copy = "pub")]
+    #[schemars(skip)]
+    #[serde(skip)]
+    target: Target,
+
+    /// Who is expected to add the label to issues and pull requests, e.g. `humans` or `prow`
+    ///
+    /// Prow's `label_sync` documents this for each label. It is set by groups and is not part of
+    /// the serialized label.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[schemars(skip)]
+    #[serde(skip)]
+    added_by: Option<String>,
+
+    /// The repositories that the label is limited to, in the format `owner/name`
+    ///
+    /// Prow's `label_sync` applies labels without repositories to every repository. The list is set
+    /// by groups and is not part of the serialized label.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    #[schemars(skip)]
+    #[serde(skip)]
+    repos: Vec<String>,
+}
+
+impl Label {
+    /// Returns the contrast of the label's color with black and white text
+    ///
+    /// This can be used to check whether the name of the label is readable, either with the text
+    /// color that GitHub picks or with the [readable text color](Contrast::readable_text_color).
+    pub fn contrast(&self) -> Contrast {
+        self.color.contrast()
+    }
+
+    /// Returns the label without its priority
+    pub fn without_priority(self) -> Self {
+        Self {
+            priority: None,
+            ..self
+        }
+    }
+
+    /// Returns the label without the flag that it belongs to an exclusive group
+    pub fn without_exclusive(self) -> Self {
+        Self {
+            exclusive: false,
+            ..self
+        }
+    }
+
+    /// Returns the label without its description if the description is empty
+    ///
+    /// Some platforms return an empty description for labels without a description.
+    pub fn without_empty_description(self) -> Self {
+        Self {
+            description: self
+                .description
+                .filter(|description| !description.get().is_empty()),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +422,132 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn description_validate() {
+        assert!(Description::new("a".repeat(100)).validate().is_ok());
+
+        let error = Description::new("a".repeat(101)).validate().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "description is 101 characters long, but GitHub allows at most 100 characters"
+        );
+    }
+
+    #[test]
+    fn timestamp_validate() {
+        assert!(Timestamp::new("2026-01-01T00:00:00Z").validate().is_ok());
+        assert!(
+            Timestamp::new("2026-01-01T00:00:00+02:00")
+                .validate()
+                .is_ok()
+        );
+        assert!(Timestamp::new("2026-01-01T00:00:00").validate().is_err());
+
+        let error = Timestamp::new("2026-01-01").validate().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "timestamp `2026-01-01` is not an RFC 3339 date and time with an offset, e.g. `2026-01-01T00:00:00Z`"
+        );
+    }
+
+    #[test]
+    fn label_contrast() {
+        let label = Label::builder()
+            .name("bug")
+            .color(Color::from_hex(0xb91c1c))
+            .build();
+
+        assert_eq!(label.contrast(), Color::from_hex(0xb91c1c).contrast());
+        assert_eq!(label.contrast().readable_text_color(), Color::WHITE);
+    }
+
+    #[test]
+    fn label_without_fields() {
+        let label = Label::builder()
+            .name("C/bug")
+            .color(Color::from_hex(0xb91c1c))
+            .description(Some("".into()))
+            .priority(Some(1))
+            .exclusive(true)
+            .target(Target::Prs)
+            .build();
+
+        let label = label
+            .without_priority()
+            .without_exclusive()
+            .without_empty_description();
+
+        assert_eq!(label.priority(), None);
+        assert!(!label.exclusive());
+        assert_eq!(label.description(), &None);
+        assert_eq!(label.target(), Target::Prs);
+    }
+
+    #[test]
+    fn label_name_validate() {
+        assert!(LabelName::new("a".repeat(50)).validate().is_ok());
+        assert!(LabelName::new("ü".repeat(50)).validate().is_ok());
+        assert!(LabelName::new(" ").validate().is_err());
+
+        let error = LabelName::new("a".repeat(51)).validate().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "label name `{}` is 51 characters long, but GitHub allows at most 50 characters",
+                "a".repeat(51)
+            )
+        );
+    }
+
+    #[test]
+    fn trait_deserialize() {
+        let yaml = indoc! {r#"
+            name: bug
+            color: '#FF0000'
+            description: a description for the label
+            aliases:
+            - defect
+        "#};
+
+        let label: Label = serde_yaml_ng::from_str(yaml).unwrap();
+        let expected = Label::builder()
+            .name("bug")
+            .color(Color::from_hex(0xff0000))
+            .description(Some("a description for the label".into()))
+            .aliases(vec!["defect".into()])
+            .build();
+
+        assert_eq!(label, expected);
+    }
+
+    #[test]
+    fn trait_deserialize_with_invalid_color() {
+        let yaml = indoc! {r#"
+            name: bug
+            color: reddish
+        "#};
+
+        let error = serde_yaml_ng::from_str::<Label>(yaml).unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("color `reddish` is not a hex code")
+        );
+    }
+
+    #[test]
+    fn trait_deserialize_with_long_name() {
+        let yaml = format!("name: {}\ncolor: '#FF0000'\n", "a".repeat(51));
+
+        let error = serde_yaml_ng::from_str::<Label>(&yaml).unwrap_err();
+
+        assert!(error.to_string().contains("is 51 characters long"));
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
@@ -84,14 +558,14 @@ mod tests {
     fn trait_serialize() {
         let label = Label::builder()
             .name("bug")
-            .color("#FF0000")
+            .color(Color::from_hex(0xff0000))
             .description(Some("a description for the label".into()))
             .build();
 
         let serialized = serde_yaml_ng::to_string(&label).unwrap();
         let expected = indoc! {r#"
             name: bug
-            color: '#FF0000'
+            color: '#ff0000'
             description: a description for the label
         "#};
 
@@ -100,12 +574,15 @@ mod tests {
 
     #[test]
     fn trait_serialize_without_description() {
-        let label = Label::builder().name("bug").color("#FF0000").build();
+        let label = Label::builder()
+            .name("bug")
+            .color(Color::from_hex(0xff0000))
+            .build();
 
         let serialized = serde_yaml_ng::to_string(&label).unwrap();
         let expected = indoc! {r#"
             name: bug
-            color: '#FF0000'
+            color: '#ff0000'
         "#};
 
         assert_eq!(serialized, expected);
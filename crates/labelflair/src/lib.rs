@@ -14,11 +14,22 @@
 #![warn(clippy::missing_docs_in_private_items)]
 
 use crate::config::v1::ConfigV1;
+use crate::diagnostic::Diagnostic;
 use crate::label::Label;
 
+pub use self::error::Error;
+
+pub mod color;
 pub mod colors;
 pub mod config;
+pub mod diagnostic;
+pub mod docs;
 pub mod label;
+pub mod output;
+pub mod plan;
+pub mod preview;
+
+mod error;
 
 /// Generate a colorful palette of labels for your GitHub Issues
 ///
@@ -32,7 +43,8 @@ impl Labelflair {
     /// Generate a list of GitHub Issues labels from the configuration
     ///
     /// This function takes a configuration and generates a list of labels based on the groups
-    /// defined in the configuration.
+    /// defined in the configuration. The configuration is validated first, and an
+    /// [`Error::Invalid`] is returned if the validation finds any errors.
     ///
     /// Given this configuration:
     ///
@@ -51,17 +63,31 @@ impl Labelflair {
     /// - name: C-feature
     ///   color: '#b91c1c'
     /// ```
-    pub fn generate(config: &ConfigV1) -> Vec<Label> {
-        let mut labels = config.labels().clone();
-        let mut labels_from_groups = config
-            .groups()
-            .iter()
-            .flat_map(|group| group.expand())
+    pub fn generate(config: &ConfigV1) -> Result<Vec<Label>, Error> {
+        let errors: Vec<Diagnostic> = config
+            .validate()
+            .into_iter()
+            .filter(Diagnostic::is_error)
             .collect();
 
-        labels.append(&mut labels_from_groups);
+        if !errors.is_empty() {
+            return Err(Error::Invalid(errors));
+        }
+
+        Self::expand(config)
+    }
+
+    /// Expand the configuration into a list of labels without validating it
+    ///
+    /// This function is meant for applications that have already validated the configuration with
+    /// [`ConfigV1::validate`], e.g. to report warnings. Otherwise, use [`Labelflair::generate`].
+    pub fn expand(config: &ConfigV1) -> Result<Vec<Label>, Error> {
+        let mut labels = config.labels().clone();
+        for group in config.groups() {
+            labels.append(&mut group.expand(config.palettes())?);
+        }
 
-        labels
+        Ok(labels)
     }
 }
 
@@ -69,6 +95,8 @@ impl Labelflair {
 mod tests {
     use indoc::indoc;
 
+    use crate::color::Color;
+
     use super::*;
 
     #[test]
@@ -90,16 +118,28 @@ mod tests {
         "##};
         let config: ConfigV1 = toml::from_str(toml).unwrap();
 
-        let mut labels = Labelflair::generate(&config);
+        let mut labels = Labelflair::generate(&config).unwrap();
         let mut expected = vec![
             Label::builder()
                 .name("good-first-issue")
-                .color("#4ade80")
+                .color(Color::from_hex(0x4ade80))
+                .build(),
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+            Label::builder()
+                .name("P-block")
+                .color(Color::from_hex(0x93c5fd))
+                .build(),
+            Label::builder()
+                .name("P-merge")
+                .color(Color::from_hex(0x1d4ed8))
                 .build(),
-            Label::builder().name("C-bug").color("#fca5a5").build(),
-            Label::builder().name("C-feature").color("#b91c1c").build(),
-            Label::builder().name("P-block").color("#93c5fd").build(),
-            Label::builder().name("P-merge").color("#1d4ed8").build(),
         ];
 
         labels.sort();
@@ -108,6 +148,44 @@ mod tests {
         assert_eq!(labels, expected);
     }
 
+    #[test]
+    fn generate_invalid_config() {
+        let toml = indoc! {r##"
+            [[label]]
+            name = "C-bug"
+            color = "#4ade80"
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "##};
+        let config: ConfigV1 = toml::from_str(toml).unwrap();
+
+        let error = Labelflair::generate(&config).unwrap_err();
+
+        assert!(matches!(error, Error::Invalid(diagnostics) if diagnostics.len() == 1));
+    }
+
+    #[test]
+    fn expand_without_validation() {
+        let toml = indoc! {r##"
+            [[label]]
+            name = "C-bug"
+            color = "#4ade80"
+
+            [[group]]
+            prefix = "C-"
+            colors = { tailwind = "red" }
+            labels = ["bug"]
+        "##};
+        let config: ConfigV1 = toml::from_str(toml).unwrap();
+
+        let labels = Labelflair::expand(&config).unwrap();
+
+        assert_eq!(labels.len(), 2);
+    }
+
     #[test]
     fn trait_send() {
         fn assert_send<T: Send>() {}
@@ -0,0 +1,205 @@
+//! Writers for the generated labels
+//!
+//! This module provides different writers that turn a list of labels into a file for other tools.
+//! Writers must implement the [`Write`] trait, which renders the labels into the file format of the
+//! writer. All writers that are built into Labelflair are listed in the [`Format`] enum.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::Error;
+use crate::label::Label;
+
+pub use self::endbug::EndBug;
+pub use self::gitea::Gitea;
+pub use self::github_labeler::GitHubLabeler;
+pub use self::gitlab::GitLab;
+pub use self::json::Json;
+pub use self::prow::Prow;
+pub use self::terraform::Terraform;
+
+mod endbug;
+mod gitea;
+mod github_labeler;
+mod gitlab;
+mod json;
+mod prow;
+mod terraform;
+
+/// Output formats in Labelflair
+///
+/// This enum represents the output formats that are built into Labelflair. Each format has its own
+/// writer, so read their documentation for more details.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Format {
+    /// The `labels.yml` file of the EndBug/label-sync action
+    #[default]
+    EndBug,
+
+    /// A label template for Gitea and Forgejo
+    Gitea,
+
+    /// The `labels.yml` file of the crazy-max/ghaction-github-labeler action
+    GitHubLabeler,
+
+    /// A JSON array of labels for the labels API of GitLab
+    GitLab,
+
+    /// A JSON array of labels
+    Json,
+
+    /// The `labels.yaml` file of Prow's `label_sync` tool
+    Prow,
+
+    /// `github_issue_label` resources for Terraform
+    Terraform,
+}
+
+/// Trait for label writers
+///
+/// This trait defines the interface for label writers in Labelflair. Each writer must implement the
+/// `write` method, which takes the generated labels and returns the content of the file. How the
+/// labels are represented is an implementation detail of the writer.
+pub trait Write {
+    /// Write the labels
+    ///
+    /// This method takes a list of labels and returns them in the format of the writer.
+    fn write(&self, labels: &[Label]) -> Result<String, Error>;
+}
+
+impl Format {
+    /// All output formats that are built into Labelflair
+    pub const ALL: [Format; 7] = [
+        Format::EndBug,
+        Format::Gitea,
+        Format::GitHubLabeler,
+        Format::GitLab,
+        Format::Json,
+        Format::Prow,
+        Format::Terraform,
+    ];
+
+    /// Returns the name of the format as it is passed on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::EndBug => "endbug",
+            Format::Gitea => "gitea",
+            Format::GitHubLabeler => "github-labeler",
+            Format::GitLab => "gitlab",
+            Format::Json => "json",
+            Format::Prow => "prow",
+            Format::Terraform => "terraform",
+        }
+    }
+
+    /// Returns the conventional name of the file for the format
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Format::EndBug | Format::GitHubLabeler => "labels.yml",
+            Format::Gitea => "gitea-labels.yaml",
+            Format::GitLab => "gitlab-labels.json",
+            Format::Json => "labels.json",
+            Format::Prow => "labels.yaml",
+            Format::Terraform => "labels.tf",
+        }
+    }
+}
+
+impl Write for Format {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let variant: Box<&dyn Write> = match self {
+            Format::EndBug => Box::new(&EndBug),
+            Format::Gitea => Box::new(&Gitea),
+            Format::GitHubLabeler => Box::new(&GitHubLabeler),
+            Format::GitLab => Box::new(&GitLab),
+            Format::Json => Box::new(&Json),
+            Format::Prow => Box::new(&Prow),
+            Format::Terraform => Box::new(&Terraform),
+        };
+
+        variant.write(labels)
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Format::ALL
+            .into_iter()
+            .find(|format| format.name() == s)
+            .ok_or_else(|| Error::UnknownFormat {
+                format: s.to_string(),
+                expected: Format::ALL.map(|format| format.name()).join(", "),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn file_name() {
+        assert_eq!(Format::EndBug.file_name(), "labels.yml");
+        assert_eq!(Format::Json.file_name(), "labels.json");
+    }
+
+    #[test]
+    fn trait_from_str() {
+        for format in Format::ALL {
+            assert_eq!(format.to_string().parse::<Format>().unwrap(), format);
+        }
+    }
+
+    #[test]
+    fn trait_from_str_with_unknown_format() {
+        let error = "xml".parse::<Format>().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "unknown output format `xml`, expected one of endbug, gitea, github-labeler, gitlab, json, prow, terraform"
+        );
+    }
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        assert_eq!(
+            Format::Json.write(&labels).unwrap(),
+            Json.write(&labels).unwrap()
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Format>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Format>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Format>();
+    }
+}
@@ -0,0 +1,140 @@
+//! Writer for the EndBug/label-sync action
+//!
+//! This module provides a writer for the `labels.yml` file of the [EndBug/label-sync] action, which
+//! creates, updates, and renames labels in a repository.
+//!
+//! [EndBug/label-sync]: https://github.com/EndBug/label-sync
+
+use serde::Serialize;
+
+use crate::Error;
+use crate::color::Color;
+use crate::label::{Label, LabelName};
+use crate::output::Write;
+
+/// Writer for the EndBug/label-sync action
+///
+/// The `EndBug` writer serializes the labels as a YAML list. Each label has a name and a color, and
+/// optionally a description and a list of aliases, which the action uses to rename labels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct EndBug;
+
+/// A label in the format of the EndBug/label-sync action
+#[derive(Clone, Debug, Serialize)]
+pub(super) struct EndBugLabel<'a> {
+    /// The name of the label
+    name: &'a str,
+
+    /// The color of the label
+    color: &'a Color,
+
+    /// The optional description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+
+    /// The previous names of the label, which the action renames to the new name
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    aliases: &'a [LabelName],
+}
+
+impl Write for EndBug {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let labels: Vec<EndBugLabel> = labels.iter().map(EndBugLabel::from).collect();
+
+        serde_yaml_ng::to_string(&labels).map_err(|error| Error::Output(error.to_string()))
+    }
+}
+
+impl<'a> From<&'a Label> for EndBugLabel<'a> {
+    fn from(label: &'a Label) -> Self {
+        EndBugLabel {
+            name: label.name().get(),
+            color: label.color(),
+            description: label
+                .description()
+                .as_ref()
+                .map(|description| description.get()),
+            aliases: label.aliases(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::label::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .aliases(vec!["bug".into()])
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let output = EndBug.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                - name: C-bug
+                  color: '#fca5a5'
+                  description: Something isn't working
+                  aliases:
+                  - bug
+                - name: C-feature
+                  color: '#b91c1c'
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_write_without_other_fields() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .priority(Some(1))
+                .delete_after(Some(Timestamp::new("2026-01-01T00:00:00Z")))
+                .build(),
+        ];
+
+        let output = EndBug.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                - name: C-bug
+                  color: '#fca5a5'
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<EndBug>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<EndBug>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<EndBug>();
+    }
+}
@@ -0,0 +1,126 @@
+//! Writer for Gitea and Forgejo
+//!
+//! This module provides a writer for the [label templates] of Gitea and Forgejo. Templates are
+//! YAML files in the `options/label` directory of the instance's custom path, and can be picked
+//! when creating a repository or organization to create all of their labels at once.
+//!
+//! [label templates]: https://docs.gitea.com/administration/customizing-gitea#labels
+
+use serde::Serialize;
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+
+/// Writer for Gitea and Forgejo
+///
+/// The `Gitea` writer serializes the labels as a label template. Labels from exclusive groups are
+/// marked as `exclusive`, which turns them into scoped labels. Gitea and Forgejo derive the scope
+/// from the part of the name before the last `/`, and allow only one label per scope on an issue.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Gitea;
+
+/// A label template for Gitea and Forgejo
+#[derive(Clone, Debug, Serialize)]
+struct Template<'a> {
+    /// The labels in the template
+    labels: Vec<GiteaLabel<'a>>,
+}
+
+/// A label in the format of a Gitea label template
+#[derive(Clone, Debug, Serialize)]
+struct GiteaLabel<'a> {
+    /// The name of the label
+    name: &'a str,
+
+    /// Whether the label is an exclusive scoped label
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    exclusive: bool,
+
+    /// The color of the label in hex format with a leading `#`
+    color: String,
+
+    /// The optional description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+impl Write for Gitea {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let template = Template {
+            labels: labels
+                .iter()
+                .map(|label| GiteaLabel {
+                    name: label.name().get(),
+                    exclusive: label.exclusive(),
+                    color: label.color().to_string(),
+                    description: label
+                        .description()
+                        .as_ref()
+                        .map(|description| description.get()),
+                })
+                .collect(),
+        };
+
+        serde_yaml_ng::to_string(&template).map_err(|error| Error::Output(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C/bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .exclusive(true)
+                .build(),
+            Label::builder()
+                .name("good first issue")
+                .color(Color::from_hex(0x4ade80))
+                .aliases(vec!["easy".into()])
+                .build(),
+        ];
+
+        let output = Gitea.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                labels:
+                - name: C/bug
+                  exclusive: true
+                  color: '#fca5a5'
+                  description: Something isn't working
+                - name: good first issue
+                  color: '#4ade80'
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Gitea>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Gitea>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Gitea>();
+    }
+}
@@ -0,0 +1,115 @@
+//! Writer for the crazy-max/ghaction-github-labeler action
+//!
+//! This module provides a writer for the `labels.yml` file of the
+//! [crazy-max/ghaction-github-labeler] action, which creates, updates, and renames labels in a
+//! repository.
+//!
+//! [crazy-max/ghaction-github-labeler]: https://github.com/crazy-max/ghaction-github-labeler
+
+use serde::Serialize;
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+
+/// Writer for the crazy-max/ghaction-github-labeler action
+///
+/// The `GitHubLabeler` writer serializes the labels as a YAML list. The action expects colors
+/// without a leading `#`, and can only rename a label from a single previous name. The first alias
+/// of a label is used as its previous name, and any other aliases are ignored.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct GitHubLabeler;
+
+/// A label in the format of the crazy-max/ghaction-github-labeler action
+#[derive(Clone, Debug, Serialize)]
+struct GitHubLabelerLabel<'a> {
+    /// The name of the label
+    name: &'a str,
+
+    /// The color of the label in hex format without a leading `#`
+    color: String,
+
+    /// The optional description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+
+    /// The previous name of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_name: Option<&'a str>,
+}
+
+impl Write for GitHubLabeler {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let labels: Vec<GitHubLabelerLabel> = labels
+            .iter()
+            .map(|label| GitHubLabelerLabel {
+                name: label.name().get(),
+                color: label.color().to_string().trim_start_matches('#').into(),
+                description: label
+                    .description()
+                    .as_ref()
+                    .map(|description| description.get()),
+                from_name: label.aliases().first().map(|alias| alias.get()),
+            })
+            .collect();
+
+        serde_yaml_ng::to_string(&labels).map_err(|error| Error::Output(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .aliases(vec!["bug".into(), "defect".into()])
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let output = GitHubLabeler.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                - name: C-bug
+                  color: fca5a5
+                  description: Something isn't working
+                  from_name: bug
+                - name: C-feature
+                  color: b91c1c
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GitHubLabeler>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GitHubLabeler>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<GitHubLabeler>();
+    }
+}
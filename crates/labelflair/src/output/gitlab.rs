@@ -0,0 +1,126 @@
+//! Writer for GitLab
+//!
+//! This module provides a writer that exports the labels in the format of the [labels API] of
+//! GitLab, so that they can be created in a project or group with a script or another tool.
+//!
+//! [labels API]: https://docs.gitlab.com/api/labels/
+
+use serde::Serialize;
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+
+/// Writer for GitLab
+///
+/// The `GitLab` writer serializes the labels as a pretty-printed JSON array. Each label is an object
+/// with the fields that the labels API of GitLab accepts. GitLab expects colors with a leading `#`,
+/// and orders prioritized labels by their priority. Labels without a priority have a priority of
+/// `null`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct GitLab;
+
+/// A label in the format of the GitLab labels API
+#[derive(Clone, Debug, Serialize)]
+struct GitLabLabel<'a> {
+    /// The name of the label
+    name: &'a str,
+
+    /// The color of the label in hex format with a leading `#`
+    color: String,
+
+    /// The optional description of the label
+    description: Option<&'a str>,
+
+    /// The optional priority of the label
+    priority: Option<u32>,
+}
+
+impl Write for GitLab {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let labels: Vec<GitLabLabel> = labels
+            .iter()
+            .map(|label| GitLabLabel {
+                name: label.name().get(),
+                color: label.color().to_string(),
+                description: label
+                    .description()
+                    .as_ref()
+                    .map(|description| description.get()),
+                priority: label.priority(),
+            })
+            .collect();
+
+        let mut json = serde_json::to_string_pretty(&labels)
+            .map_err(|error| Error::Output(error.to_string()))?;
+        json.push('\n');
+
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .aliases(vec!["feature".into()])
+                .priority(Some(1))
+                .build(),
+        ];
+
+        let output = GitLab.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r##"
+                [
+                  {
+                    "name": "C-bug",
+                    "color": "#fca5a5",
+                    "description": "Something isn't working",
+                    "priority": null
+                  },
+                  {
+                    "name": "C-feature",
+                    "color": "#b91c1c",
+                    "description": null,
+                    "priority": 1
+                  }
+                ]
+            "##}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<GitLab>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<GitLab>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<GitLab>();
+    }
+}
@@ -0,0 +1,125 @@
+//! Writer for JSON
+//!
+//! This module provides a writer that serializes the labels as a JSON array, which can be consumed
+//! by scripts and other tools without any post-processing.
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+use crate::output::endbug::EndBugLabel;
+
+/// Writer for JSON
+///
+/// The `Json` writer serializes the labels as a pretty-printed JSON array. Each label is an object
+/// with the same fields as in the `labels.yml` file of the EndBug/label-sync action.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Json;
+
+impl Write for Json {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let labels: Vec<EndBugLabel> = labels.iter().map(EndBugLabel::from).collect();
+
+        let mut json = serde_json::to_string_pretty(&labels)
+            .map_err(|error| Error::Output(error.to_string()))?;
+        json.push('\n');
+
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+    use crate::label::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .aliases(vec!["feature".into()])
+                .build(),
+        ];
+
+        let output = Json.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r##"
+                [
+                  {
+                    "name": "C-bug",
+                    "color": "#fca5a5",
+                    "description": "Something isn't working"
+                  },
+                  {
+                    "name": "C-feature",
+                    "color": "#b91c1c",
+                    "aliases": [
+                      "feature"
+                    ]
+                  }
+                ]
+            "##}
+        );
+    }
+
+    #[test]
+    fn trait_write_without_labels() {
+        assert_eq!(Json.write(&[]).unwrap(), "[]\n");
+    }
+
+    #[test]
+    fn trait_write_without_other_fields() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .priority(Some(1))
+                .delete_after(Some(Timestamp::new("2026-01-01T00:00:00Z")))
+                .build(),
+        ];
+
+        let output = Json.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r##"
+                [
+                  {
+                    "name": "C-bug",
+                    "color": "#fca5a5"
+                  }
+                ]
+            "##}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Json>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Json>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Json>();
+    }
+}
@@ -0,0 +1,257 @@
+//! Writer for Prow's `label_sync`
+//!
+//! This module provides a writer for the `labels.yaml` file of Prow's [label_sync] tool, which
+//! manages the labels of many repositories in a GitHub organization.
+//!
+//! [label_sync]: https://github.com/kubernetes-sigs/prow/tree/main/cmd/label_sync
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::Error;
+use crate::label::{Label, Target};
+use crate::output::Write;
+
+/// Writer for Prow's `label_sync`
+///
+/// The `Prow` writer puts labels without repositories into the `default` section of the file, which
+/// `label_sync` applies to every repository. Labels that are limited to repositories are written to
+/// the `repos` section under each of their repositories instead. Aliases of a label are written as
+/// its `previously` names, which `label_sync` uses to rename labels. Every label has a `target`,
+/// which is set by its group and defaults to `both`, and optionally `addedBy` and `deleteAfter`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Prow;
+
+/// The top-level structure of the `labels.yaml` file
+#[derive(Clone, Debug, Serialize)]
+struct ProwConfig<'a> {
+    /// The labels that are applied to every repository
+    default: ProwRepo<'a>,
+
+    /// The labels that are applied to individual repositories, indexed by `owner/name`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    repos: BTreeMap<&'a str, ProwRepo<'a>>,
+}
+
+/// The labels for a repository
+#[derive(Clone, Debug, Default, Serialize)]
+struct ProwRepo<'a> {
+    /// The labels of the repository
+    labels: Vec<ProwLabel<'a>>,
+}
+
+/// A label in the format of Prow's `label_sync`
+#[derive(Clone, Debug, Serialize)]
+struct ProwLabel<'a> {
+    /// The name of the label
+    name: &'a str,
+
+    /// The color of the label in hex format without a leading `#`
+    color: String,
+
+    /// The optional description of the label
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+
+    /// Whether the label is meant for issues, pull requests, or both
+    target: Target,
+
+    /// Who is expected to add the label
+    #[serde(rename = "addedBy", skip_serializing_if = "Option::is_none")]
+    added_by: Option<&'a str>,
+
+    /// The time after which the label is deleted
+    #[serde(rename = "deleteAfter", skip_serializing_if = "Option::is_none")]
+    delete_after: Option<&'a str>,
+
+    /// The previous names of the label
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    previously: Vec<PreviousLabel<'a>>,
+}
+
+/// A previous name of a label
+///
+/// `label_sync` requires the same fields for previous labels as for current labels, so the color is
+/// repeated.
+#[derive(Clone, Debug, Serialize)]
+struct PreviousLabel<'a> {
+    /// The previous name of the label
+    name: &'a str,
+
+    /// The color of the label in hex format without a leading `#`
+    color: String,
+}
+
+impl Write for Prow {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let mut config = ProwConfig {
+            default: ProwRepo::default(),
+            repos: BTreeMap::new(),
+        };
+
+        for label in labels {
+            let prow_label = ProwLabel::from(label);
+
+            if label.repos().is_empty() {
+                config.default.labels.push(prow_label);
+                continue;
+            }
+
+            for repo in label.repos() {
+                config
+                    .repos
+                    .entry(repo.as_str())
+                    .or_default()
+                    .labels
+                    .push(prow_label.clone());
+            }
+        }
+
+        serde_yaml_ng::to_string(&config).map_err(|error| Error::Output(error.to_string()))
+    }
+}
+
+impl<'a> From<&'a Label> for ProwLabel<'a> {
+    fn from(label: &'a Label) -> Self {
+        let color = label
+            .color()
+            .to_string()
+            .trim_start_matches('#')
+            .to_string();
+
+        ProwLabel {
+            name: label.name().get(),
+            color: color.clone(),
+            description: label
+                .description()
+                .as_ref()
+                .map(|description| description.get()),
+            target: label.target(),
+            added_by: label.added_by().as_deref(),
+            delete_after: label
+                .delete_after()
+                .as_ref()
+                .map(|timestamp| timestamp.get()),
+            previously: label
+                .aliases()
+                .iter()
+                .map(|alias| PreviousLabel {
+                    name: alias.get(),
+                    color: color.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+    use crate::label::Timestamp;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("kind/bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something isn't working".into()))
+                .aliases(vec!["bug".into(), "C-bug".into()])
+                .target(Target::Issues)
+                .build(),
+            Label::builder()
+                .name("lgtm")
+                .color(Color::from_hex(0x15803d))
+                .build(),
+        ];
+
+        let output = Prow.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                default:
+                  labels:
+                  - name: kind/bug
+                    color: fca5a5
+                    description: Something isn't working
+                    target: issues
+                    previously:
+                    - name: bug
+                      color: fca5a5
+                    - name: C-bug
+                      color: fca5a5
+                  - name: lgtm
+                    color: 15803d
+                    target: both
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_write_with_repos() {
+        let labels = vec![
+            Label::builder()
+                .name("lgtm")
+                .color(Color::from_hex(0x15803d))
+                .added_by("prow".to_string())
+                .build(),
+            Label::builder()
+                .name("needs-rebase")
+                .color(Color::from_hex(0xb91c1c))
+                .delete_after(Some(Timestamp::new("2026-01-01T00:00:00Z")))
+                .repos(vec!["jdno/labelflair".into(), "jdno/website".into()])
+                .build(),
+        ];
+
+        let output = Prow.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                default:
+                  labels:
+                  - name: lgtm
+                    color: 15803d
+                    target: both
+                    addedBy: prow
+                repos:
+                  jdno/labelflair:
+                    labels:
+                    - name: needs-rebase
+                      color: b91c1c
+                      target: both
+                      deleteAfter: 2026-01-01T00:00:00Z
+                  jdno/website:
+                    labels:
+                    - name: needs-rebase
+                      color: b91c1c
+                      target: both
+                      deleteAfter: 2026-01-01T00:00:00Z
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Prow>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Prow>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Prow>();
+    }
+}
@@ -0,0 +1,315 @@
+//! Writer for Terraform
+//!
+//! This module provides a writer that turns the labels into [`github_issue_label`] resources for
+//! the GitHub provider of Terraform, so that labels can be managed together with the repositories.
+//!
+//! [`github_issue_label`]: https://registry.terraform.io/providers/integrations/github/latest/docs/resources/issue_label
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+use crate::Error;
+use crate::label::{Label, LabelName};
+use crate::output::Write;
+
+/// The type of the Terraform resource for a label
+const RESOURCE_TYPE: &str = "github_issue_label";
+
+/// Writer for Terraform
+///
+/// The `Terraform` writer creates a `github_issue_label` resource for each label. The repository is
+/// read from the `repository` variable, which is declared at the top of the file. Each resource is
+/// named after its label, so its address stays the same as long as the label is not renamed.
+/// Resource names only contain lowercase letters, digits, and underscores. Labels whose names are
+/// turned into the same resource name are rejected, since numbering them would change their
+/// addresses whenever labels are added or reordered.
+///
+/// Labels can be renamed by listing their previous names as aliases. For every alias, the writer
+/// adds a `moved` block that points from the address of the old name to the address of the new
+/// name, which tells Terraform to update the existing label instead of replacing it.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Terraform;
+
+impl Write for Terraform {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let addresses = addresses(labels)?;
+        let taken: HashSet<&str> = addresses.iter().map(String::as_str).collect();
+        let mut moves: HashMap<String, &str> = HashMap::new();
+
+        let mut hcl = String::new();
+
+        hcl.push_str("variable \"repository\" {\n");
+        hcl.push_str("  description = \"The name of the repository for the labels\"\n");
+        hcl.push_str("  type        = string\n");
+        hcl.push_str("}\n");
+
+        for (label, address) in labels.iter().zip(&addresses) {
+            let _ = writeln!(hcl);
+            let _ = writeln!(hcl, "resource \"{RESOURCE_TYPE}\" \"{address}\" {{");
+            let _ = writeln!(hcl, "  repository  = var.repository");
+            let _ = writeln!(hcl, "  name        = {}", quote(label.name().get()));
+            let _ = writeln!(
+                hcl,
+                "  color       = \"{}\"",
+                label.color().to_string().trim_start_matches('#')
+            );
+
+            if let Some(description) = label.description() {
+                let _ = writeln!(hcl, "  description = {}", quote(description.get()));
+            }
+
+            let _ = writeln!(hcl, "}}");
+
+            for alias in label.aliases() {
+                let from = identifier(alias);
+
+                // Terraform rejects moved blocks whose source is still declared in the
+                // configuration, which includes moves from an address to itself
+                if taken.contains(from.as_str()) {
+                    continue;
+                }
+
+                // Aliases that are turned into the same resource name need only one moved block,
+                // but the same resource cannot be moved to two different addresses
+                match moves.get(&from) {
+                    Some(to) if *to == address => continue,
+                    Some(to) => {
+                        return Err(Error::Output(format!(
+                            "the alias `{alias}` of label `{}` would move the Terraform resource `{from}`, which is already moved to `{to}`",
+                            label.name()
+                        )));
+                    }
+                    None => {
+                        moves.insert(from.clone(), address);
+                    }
+                }
+
+                let _ = writeln!(hcl);
+                let _ = writeln!(hcl, "moved {{");
+                let _ = writeln!(hcl, "  from = {RESOURCE_TYPE}.{from}");
+                let _ = writeln!(hcl, "  to   = {RESOURCE_TYPE}.{address}");
+                let _ = writeln!(hcl, "}}");
+            }
+        }
+
+        Ok(hcl)
+    }
+}
+
+/// Returns a unique resource name for each label
+///
+/// The names are derived from the label names. Returns an error if two labels end up with the same
+/// name, since the resource name of a label must not depend on the other labels.
+fn addresses(labels: &[Label]) -> Result<Vec<String>, Error> {
+    let mut seen: HashMap<String, &LabelName> = HashMap::new();
+
+    labels
+        .iter()
+        .map(|label| {
+            let address = identifier(label.name());
+
+            if let Some(other) = seen.insert(address.clone(), label.name()) {
+                return Err(Error::Output(format!(
+                    "the labels `{other}` and `{}` would both use the Terraform resource name `{address}`",
+                    label.name()
+                )));
+            }
+
+            Ok(address)
+        })
+        .collect()
+}
+
+/// Convert a label name into a Terraform identifier
+///
+/// The name is converted to lowercase, and every run of characters that are not ASCII letters or
+/// digits is replaced with a single underscore. Identifiers that would not start with a letter are
+/// prefixed with `label_`.
+fn identifier(name: &LabelName) -> String {
+    let mut identifier = String::new();
+
+    for c in name.get().chars() {
+        if c.is_ascii_alphanumeric() {
+            identifier.push(c.to_ascii_lowercase());
+        } else if !identifier.is_empty() && !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+
+    let identifier = identifier.trim_end_matches('_');
+
+    if identifier.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        identifier.to_string()
+    } else if identifier.is_empty() {
+        "label".to_string()
+    } else {
+        format!("label_{identifier}")
+    }
+}
+
+/// Quote a string for HCL
+///
+/// Besides the usual escape sequences, HCL requires the template sequences `${` and `%{` to be
+/// escaped by doubling their first character.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    let mut chars = value.chars().peekable();
+
+    quoted.push('"');
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '$' | '%' if chars.peek() == Some(&'{') => {
+                quoted.push(c);
+                quoted.push(c);
+            }
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn identifier_from_name() {
+        assert_eq!(identifier(&"C-bug".into()), "c_bug");
+        assert_eq!(identifier(&"good first issue".into()), "good_first_issue");
+        assert_eq!(identifier(&"💥 breaking".into()), "breaking");
+        assert_eq!(identifier(&"1.0".into()), "label_1_0");
+        assert_eq!(identifier(&"🚀".into()), "label");
+    }
+
+    #[test]
+    fn quote_escapes_strings() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(quote("say \"hi\"\n"), "\"say \\\"hi\\\"\\n\"");
+        assert_eq!(quote("C:\\path"), "\"C:\\\\path\"");
+        assert_eq!(quote("${var} and %{if}"), "\"$${var} and %%{if}\"");
+        assert_eq!(quote("100% $5"), "\"100% $5\"");
+    }
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Something \"isn't\" working".into()))
+                .aliases(vec!["bug".into(), "c bug".into()])
+                .build(),
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let output = Terraform.write(&labels).unwrap();
+
+        assert_eq!(
+            output,
+            indoc! {r#"
+                variable "repository" {
+                  description = "The name of the repository for the labels"
+                  type        = string
+                }
+
+                resource "github_issue_label" "c_bug" {
+                  repository  = var.repository
+                  name        = "C-bug"
+                  color       = "fca5a5"
+                  description = "Something \"isn't\" working"
+                }
+
+                moved {
+                  from = github_issue_label.bug
+                  to   = github_issue_label.c_bug
+                }
+
+                resource "github_issue_label" "c_feature" {
+                  repository  = var.repository
+                  name        = "C-feature"
+                  color       = "b91c1c"
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn trait_write_with_colliding_addresses() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+            Label::builder()
+                .name("C bug")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let error = Terraform.write(&labels).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "failed to write the labels: the labels `C-bug` and `C bug` would both use the Terraform resource name `c_bug`"
+        );
+    }
+
+    #[test]
+    fn trait_write_with_colliding_aliases() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .aliases(vec!["bug".into(), "Bug".into()])
+                .build(),
+            Label::builder()
+                .name("C-defect")
+                .color(Color::from_hex(0xb91c1c))
+                .aliases(vec!["BUG".into()])
+                .build(),
+        ];
+
+        let error = Terraform.write(&labels).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "failed to write the labels: the alias `BUG` of label `C-defect` would move the Terraform resource `bug`, which is already moved to `c_bug`"
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Terraform>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Terraform>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Terraform>();
+    }
+}
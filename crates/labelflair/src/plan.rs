@@ -0,0 +1,546 @@
+//! Plan the changes to the labels in a repository
+//!
+//! This module compares the labels that have been generated by Labelflair with the labels that
+//! already exist in a repository, and produces a [`Plan`] with the [`Operation`]s that turn the
+//! existing labels into the desired labels. The planner does not talk to any API, so every backend
+//! and every output can share the same algorithm.
+
+use std::collections::HashMap;
+
+use getset::Getters;
+
+use crate::color::Color;
+use crate::label::{Description, Label, LabelName};
+
+/// A plan with the operations to synchronize labels
+///
+/// The plan contains an [`Operation`] for every desired and every existing label. Operations are
+/// ordered so that they can be applied one after another: deletions come first to free up their
+/// names, followed by renames, updates, and creations. Unchanged labels are listed last.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Getters)]
+pub struct Plan {
+    /// The operations in the plan
+    #[getset(get = "pub")]
+    operations: Vec<Operation>,
+}
+
+/// An operation on a single label
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Operation {
+    /// Delete an existing label that is not part of the desired labels
+    Delete(Label),
+
+    /// Rename an existing label, and optionally change its other fields
+    Rename {
+        /// The current name of the label
+        from: LabelName,
+        /// The desired state of the label
+        label: Label,
+        /// The fields other than the name that change
+        changes: Vec<Change>,
+    },
+
+    /// Update the fields of an existing label
+    Update {
+        /// The desired state of the label
+        label: Label,
+        /// The fields that change
+        changes: Vec<Change>,
+    },
+
+    /// Create a new label
+    Create(Label),
+
+    /// Keep an existing label as it is
+    Unchanged(Label),
+}
+
+/// A change to a field of a label
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Change {
+    /// The color of the label changes
+    Color {
+        /// The current color
+        from: Color,
+        /// The desired color
+        to: Color,
+    },
+
+    /// The description of the label changes
+    Description {
+        /// The current description
+        from: Option<Description>,
+        /// The desired description
+        to: Option<Description>,
+    },
+
+    /// Whether the label is exclusive changes
+    Exclusive {
+        /// Whether the label is currently exclusive
+        from: bool,
+        /// Whether the label should be exclusive
+        to: bool,
+    },
+
+    /// The priority of the label changes
+    Priority {
+        /// The current priority
+        from: Option<u32>,
+        /// The desired priority
+        to: Option<u32>,
+    },
+}
+
+impl Plan {
+    /// Plan the operations that turn the existing labels into the desired labels
+    ///
+    /// GitHub compares label names case-insensitively, so names are matched without regard to
+    /// their case. Desired labels are first matched against existing labels by their name, and then
+    /// by their aliases. A label that matches through an alias is renamed instead of deleted and
+    /// recreated, so that its assignments to issues and pull requests survive.
+    pub fn new(desired: &[Label], existing: &[Label]) -> Self {
+        let mut existing: HashMap<String, &Label> = existing
+            .iter()
+            .map(|label| (normalize(label.name()), label))
+            .collect();
+
+        let mut matched = Vec::new();
+        let mut unmatched = Vec::new();
+
+        for label in desired {
+            match existing.remove(&normalize(label.name())) {
+                Some(current) => matched.push((current, label)),
+                None => unmatched.push(label),
+            }
+        }
+
+        let mut created = Vec::new();
+
+        for label in unmatched {
+            let current = label
+                .aliases()
+                .iter()
+                .find_map(|alias| existing.remove(&normalize(alias)));
+
+            match current {
+                Some(current) => matched.push((current, label)),
+                None => created.push(label),
+            }
+        }
+
+        let mut operations: Vec<Operation> = existing
+            .into_values()
+            .map(|label| Operation::Delete(label.clone()))
+            .collect();
+
+        operations.extend(
+            matched
+                .into_iter()
+                .map(|(current, label)| Operation::between(current, label)),
+        );
+        operations.extend(
+            created
+                .into_iter()
+                .map(|label| Operation::Create(label.clone())),
+        );
+
+        operations.sort_by(|a, b| a.rank().cmp(&b.rank()).then(a.name().cmp(b.name())));
+
+        Self { operations }
+    }
+
+    /// Returns `true` if the plan contains operations that change labels
+    pub fn has_changes(&self) -> bool {
+        self.operations.iter().any(Operation::is_change)
+    }
+}
+
+impl Operation {
+    /// Returns the name of the label after the operation has been applied
+    ///
+    /// For deletions, this is the name of the label that is deleted.
+    pub fn name(&self) -> &LabelName {
+        match self {
+            Operation::Delete(label)
+            | Operation::Rename { label, .. }
+            | Operation::Update { label, .. }
+            | Operation::Create(label)
+            | Operation::Unchanged(label) => label.name(),
+        }
+    }
+
+    /// Returns `true` if the operation changes a label
+    pub fn is_change(&self) -> bool {
+        !matches!(self, Operation::Unchanged(_))
+    }
+
+    /// Create the operation that turns the current label into the desired label
+    fn between(current: &Label, desired: &Label) -> Self {
+        let mut changes = Vec::new();
+
+        if current.color() != desired.color() {
+            changes.push(Change::Color {
+                from: *current.color(),
+                to: *desired.color(),
+            });
+        }
+
+        if current.description() != desired.description() {
+            changes.push(Change::Description {
+                from: current.description().clone(),
+                to: desired.description().clone(),
+            });
+        }
+
+        if current.exclusive() != desired.exclusive() {
+            changes.push(Change::Exclusive {
+                from: current.exclusive(),
+                to: desired.exclusive(),
+            });
+        }
+
+        if current.priority() != desired.priority() {
+            changes.push(Change::Priority {
+                from: current.priority(),
+                to: desired.priority(),
+            });
+        }
+
+        if current.name() != desired.name() {
+            Operation::Rename {
+                from: current.name().clone(),
+                label: desired.clone(),
+                changes,
+            }
+        } else if !changes.is_empty() {
+            Operation::Update {
+                label: desired.clone(),
+                changes,
+            }
+        } else {
+            Operation::Unchanged(desired.clone())
+        }
+    }
+
+    /// Returns the position of the operation in a plan
+    fn rank(&self) -> usize {
+        match self {
+            Operation::Delete(_) => 0,
+            Operation::Rename { .. } => 1,
+            Operation::Update { .. } => 2,
+            Operation::Create(_) => 3,
+            Operation::Unchanged(_) => 4,
+        }
+    }
+}
+
+/// Normalize a label name for case-insensitive comparisons
+fn normalize(name: &LabelName) -> String {
+    name.to_string().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_creates_missing_labels() {
+        let desired = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &[]);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Create(desired[0].clone())]
+        );
+    }
+
+    #[test]
+    fn new_deletes_other_labels() {
+        let existing = vec![
+            Label::builder()
+                .name("wontfix")
+                .color(Color::from_hex(0xffffff))
+                .build(),
+        ];
+
+        let plan = Plan::new(&[], &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Delete(existing[0].clone())]
+        );
+    }
+
+    #[test]
+    fn new_keeps_unchanged_labels() {
+        let desired = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Unchanged(desired[0].clone())]
+        );
+        assert!(!plan.has_changes());
+    }
+
+    #[test]
+    fn new_updates_changed_fields() {
+        let desired = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .description(Some("Report a bug".into()))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Update {
+                label: desired[0].clone(),
+                changes: vec![
+                    Change::Color {
+                        from: Color::from_hex(0xb91c1c),
+                        to: Color::from_hex(0xfca5a5),
+                    },
+                    Change::Description {
+                        from: None,
+                        to: Some("Report a bug".into()),
+                    },
+                ],
+            }]
+        );
+        assert!(plan.has_changes());
+    }
+
+    #[test]
+    fn new_updates_changed_exclusive() {
+        let desired = vec![
+            Label::builder()
+                .name("C/bug")
+                .color(Color::from_hex(0xfca5a5))
+                .exclusive(true)
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C/bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Update {
+                label: desired[0].clone(),
+                changes: vec![Change::Exclusive {
+                    from: false,
+                    to: true,
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn new_updates_changed_priority() {
+        let desired = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .priority(Some(1))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Update {
+                label: desired[0].clone(),
+                changes: vec![Change::Priority {
+                    from: None,
+                    to: Some(1),
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn new_renames_labels_through_aliases() {
+        let desired = vec![
+            Label::builder()
+                .name("C-defect")
+                .color(Color::from_hex(0xfca5a5))
+                .aliases(vec!["bug".into()])
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("Bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Rename {
+                from: "Bug".into(),
+                label: desired[0].clone(),
+                changes: Vec::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn new_renames_labels_with_different_case() {
+        let desired = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("c-BUG")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![Operation::Rename {
+                from: "c-BUG".into(),
+                label: desired[0].clone(),
+                changes: Vec::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn new_prefers_names_over_aliases() {
+        let desired = vec![
+            Label::builder()
+                .name("C-defect")
+                .color(Color::from_hex(0xfca5a5))
+                .aliases(vec!["C-bug".into()])
+                .build(),
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+
+        assert_eq!(
+            plan.operations(),
+            &vec![
+                Operation::Create(desired[0].clone()),
+                Operation::Unchanged(desired[1].clone()),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_orders_operations() {
+        let desired = vec![
+            Label::builder()
+                .name("C-feature")
+                .color(Color::from_hex(0xb91c1c))
+                .build(),
+            Label::builder()
+                .name("C-defect")
+                .color(Color::from_hex(0xfca5a5))
+                .aliases(vec!["bug".into()])
+                .build(),
+            Label::builder()
+                .name("C-docs")
+                .color(Color::from_hex(0xef4444))
+                .build(),
+        ];
+        let existing = vec![
+            Label::builder()
+                .name("C-docs")
+                .color(Color::from_hex(0xef4444))
+                .build(),
+            Label::builder()
+                .name("bug")
+                .color(Color::from_hex(0xfca5a5))
+                .build(),
+            Label::builder()
+                .name("wontfix")
+                .color(Color::from_hex(0xffffff))
+                .build(),
+        ];
+
+        let plan = Plan::new(&desired, &existing);
+        let names: Vec<String> = plan
+            .operations()
+            .iter()
+            .map(|operation| operation.name().to_string())
+            .collect();
+
+        assert_eq!(names, vec!["wontfix", "C-defect", "C-feature", "C-docs"]);
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Plan>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Plan>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Plan>();
+    }
+}
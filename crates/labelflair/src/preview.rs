@@ -0,0 +1,204 @@
+//! Previews of the generated labels
+//!
+//! Picking colors for labels is easier when the labels can be seen. This module renders the labels
+//! as pills that look like the labels on GitHub, in both the light and the dark theme. The previews
+//! implement the [`Write`](crate::output::Write) trait, so they can be written like any other
+//! output, e.g. as an artifact of a pull request that changes the palette.
+
+use crate::color::{Color, Hsl};
+
+pub use self::html::Html;
+pub use self::svg::Svg;
+
+mod html;
+mod svg;
+
+/// The themes in which the labels are previewed
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Theme {
+    /// The light theme of GitHub
+    #[default]
+    Light,
+
+    /// The dark theme of GitHub
+    Dark,
+}
+
+/// The colors of a label in a theme
+///
+/// GitHub does not draw labels with their color as is. In the light theme, the text is either
+/// black or white depending on the lightness of the label, and very light labels get a border. In
+/// the dark theme, the label is drawn as a translucent pill with a lightened text color.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+struct Style {
+    /// The color of the background
+    background: Color,
+
+    /// The opacity of the background between `0.0` and `1.0`
+    background_opacity: f64,
+
+    /// The color of the text
+    text: Color,
+
+    /// The color of the border
+    border: Color,
+
+    /// The opacity of the border between `0.0` and `1.0`
+    border_opacity: f64,
+}
+
+impl Theme {
+    /// All themes in the order in which they are previewed
+    pub const ALL: [Theme; 2] = [Theme::Light, Theme::Dark];
+
+    /// Returns the name of the theme
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Light => "Light",
+            Theme::Dark => "Dark",
+        }
+    }
+
+    /// Returns the background color of the page
+    fn background(&self) -> &'static str {
+        match self {
+            Theme::Light => "#ffffff",
+            Theme::Dark => "#0d1117",
+        }
+    }
+
+    /// Returns the color of text on the page
+    fn foreground(&self) -> &'static str {
+        match self {
+            Theme::Light => "#1f2328",
+            Theme::Dark => "#e6edf3",
+        }
+    }
+}
+
+impl Style {
+    /// Compute the style of a label in the given theme
+    ///
+    /// The computation follows the stylesheet that GitHub uses for labels, which is based on the
+    /// perceived lightness of the label color.
+    fn new(color: Color, theme: Theme) -> Self {
+        let lightness = color.perceived_lightness();
+        let hsl = color.to_hsl();
+
+        match theme {
+            Theme::Light => Self {
+                background: color,
+                background_opacity: 1.0,
+                text: color.text_color(),
+                border: Hsl::new(hsl.hue(), hsl.saturation(), hsl.lightness() - 0.25).to_color(),
+                border_opacity: ((lightness - 0.96) * 100.0).clamp(0.0, 1.0),
+            },
+            Theme::Dark => {
+                let lighten_by = if lightness < 0.6 {
+                    0.6 - lightness
+                } else {
+                    0.0
+                };
+                let text =
+                    Hsl::new(hsl.hue(), hsl.saturation(), hsl.lightness() + lighten_by).to_color();
+
+                Self {
+                    background: color,
+                    background_opacity: 0.18,
+                    text,
+                    border: text,
+                    border_opacity: 0.3,
+                }
+            }
+        }
+    }
+}
+
+/// Format a color with an opacity for CSS
+fn css(color: Color, opacity: f64) -> String {
+    if opacity >= 1.0 {
+        return color.to_string();
+    }
+
+    let (red, green, blue) = color.to_rgb();
+
+    format!("rgba({red}, {green}, {blue}, {opacity})")
+}
+
+/// Escape text for HTML and XML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn style_light_theme() {
+        let dark = Style::new(Color::from_hex(0xb91c1c), Theme::Light);
+        let light = Style::new(Color::from_hex(0xfafafa), Theme::Light);
+
+        assert_eq!(dark.text, Color::new(255, 255, 255));
+        assert_eq!(dark.border_opacity, 0.0);
+        assert_eq!(light.text, Color::new(0, 0, 0));
+        assert!(light.border_opacity > 0.0);
+    }
+
+    #[test]
+    fn style_dark_theme() {
+        let style = Style::new(Color::from_hex(0xb91c1c), Theme::Dark);
+
+        assert_eq!(style.background, Color::from_hex(0xb91c1c));
+        assert_eq!(style.background_opacity, 0.18);
+        assert!(style.text.to_hsl().lightness() > Color::from_hex(0xb91c1c).to_hsl().lightness());
+    }
+
+    #[test]
+    fn css_with_opacity() {
+        assert_eq!(css(Color::from_hex(0xb91c1c), 1.0), "#b91c1c");
+        assert_eq!(
+            css(Color::from_hex(0xb91c1c), 0.18),
+            "rgba(185, 28, 28, 0.18)"
+        );
+    }
+
+    #[test]
+    fn escape_markup() {
+        assert_eq!(
+            escape("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Theme>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Theme>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Theme>();
+    }
+}
@@ -0,0 +1,150 @@
+//! Preview as an HTML page
+//!
+//! This module provides a writer that renders the labels into a standalone HTML page. The page has
+//! no external dependencies, so it can be opened directly from a build artifact.
+
+use std::fmt::Write as _;
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+
+use super::{Style, Theme, css, escape};
+
+/// The stylesheet of the preview
+const STYLESHEET: &str = r#"body {
+  margin: 0;
+  font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", "Noto Sans", Helvetica, Arial, sans-serif;
+}
+
+section {
+  padding: 24px;
+}
+
+h2 {
+  margin: 0 0 16px;
+  font-size: 16px;
+}
+
+.labels {
+  display: flex;
+  flex-wrap: wrap;
+  gap: 8px;
+}
+
+.label {
+  padding: 0 7px;
+  border: 1px solid transparent;
+  border-radius: 2em;
+  font-size: 12px;
+  font-weight: 500;
+  line-height: 18px;
+  white-space: nowrap;
+}
+"#;
+
+/// Preview as an HTML page
+///
+/// The `Html` writer renders a section for each [`Theme`], in which every label is drawn as a pill
+/// with the colors that GitHub would use. The description of a label is shown as a tooltip.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Html;
+
+impl Write for Html {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let mut html = String::new();
+
+        html.push_str("<!DOCTYPE html>\n");
+        html.push_str("<html lang=\"en\">\n");
+        html.push_str("<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n");
+        html.push_str("<title>Labelflair preview</title>\n");
+        let _ = write!(html, "<style>\n{STYLESHEET}</style>\n");
+        html.push_str("</head>\n");
+        html.push_str("<body>\n");
+
+        for theme in Theme::ALL {
+            let _ = writeln!(
+                html,
+                "<section style=\"background-color: {}; color: {}\">",
+                theme.background(),
+                theme.foreground()
+            );
+            let _ = writeln!(html, "<h2>{}</h2>", theme.name());
+            html.push_str("<div class=\"labels\">\n");
+
+            for label in labels {
+                let style = Style::new(*label.color(), theme);
+                let title = label
+                    .description()
+                    .as_ref()
+                    .map(|description| format!(" title=\"{}\"", escape(description.get())))
+                    .unwrap_or_default();
+
+                let _ = writeln!(
+                    html,
+                    "<span class=\"label\" style=\"background-color: {}; color: {}; border-color: {}\"{title}>{}</span>",
+                    css(style.background, style.background_opacity),
+                    css(style.text, 1.0),
+                    css(style.border, style.border_opacity),
+                    escape(label.name().get()),
+                );
+            }
+
+            html.push_str("</div>\n");
+            html.push_str("</section>\n");
+        }
+
+        html.push_str("</body>\n");
+        html.push_str("</html>\n");
+
+        Ok(html)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::Color;
+
+    use super::*;
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![
+            Label::builder()
+                .name("C-bug")
+                .color(Color::from_hex(0xb91c1c))
+                .description(Some("Something <isn't> working".into()))
+                .build(),
+        ];
+
+        let output = Html.write(&labels).unwrap();
+
+        assert!(output.starts_with("<!DOCTYPE html>\n"));
+        assert!(output.contains("<h2>Light</h2>"));
+        assert!(output.contains("<h2>Dark</h2>"));
+        assert!(output.contains(
+            "<span class=\"label\" style=\"background-color: #b91c1c; color: #ffffff; border-color: rgba(74, 11, 11, 0)\" title=\"Something &lt;isn&#39;t&gt; working\">C-bug</span>"
+        ));
+        assert!(output.contains("background-color: rgba(185, 28, 28, 0.18)"));
+        assert!(output.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Html>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Html>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Html>();
+    }
+}
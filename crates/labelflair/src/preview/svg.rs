@@ -0,0 +1,214 @@
+//! Preview as an SVG image
+//!
+//! This module provides a writer that renders the labels into an SVG image, which can be embedded
+//! in Markdown, e.g. in a comment on a pull request.
+
+use std::fmt::Write as _;
+
+use crate::Error;
+use crate::label::Label;
+use crate::output::Write;
+
+use super::{Style, Theme, escape};
+
+/// The width of the image
+const WIDTH: f64 = 800.0;
+
+/// The space around the labels in each theme
+const PADDING: f64 = 24.0;
+
+/// The height of the title of each theme, including the space below it
+const TITLE_HEIGHT: f64 = 32.0;
+
+/// The height of a label
+const LABEL_HEIGHT: f64 = 20.0;
+
+/// The space between two labels
+const GAP: f64 = 8.0;
+
+/// The estimated average width of a character in a label
+///
+/// SVG has no text layout, so the width of a label is estimated from the number of characters in
+/// its name.
+const CHARACTER_WIDTH: f64 = 7.0;
+
+/// Preview as an SVG image
+///
+/// The `Svg` writer renders a panel for each [`Theme`], in which every label is drawn as a pill
+/// with the colors that GitHub would use. Labels wrap into rows that fit the width of the image.
+/// The description of a label is shown as a tooltip.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Svg;
+
+impl Write for Svg {
+    fn write(&self, labels: &[Label]) -> Result<String, Error> {
+        let (positions, rows) = layout(labels);
+        let panel_height = 2.0 * PADDING + TITLE_HEIGHT + rows_height(rows);
+        let height = panel_height * Theme::ALL.len() as f64;
+
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" viewBox=\"0 0 {WIDTH} {height}\" font-family=\"-apple-system, BlinkMacSystemFont, 'Segoe UI', 'Noto Sans', Helvetica, Arial, sans-serif\">"
+        );
+
+        for (index, theme) in Theme::ALL.into_iter().enumerate() {
+            let top = panel_height * index as f64;
+
+            let _ = writeln!(
+                svg,
+                "<rect x=\"0\" y=\"{top}\" width=\"{WIDTH}\" height=\"{panel_height}\" fill=\"{}\"/>",
+                theme.background()
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{PADDING}\" y=\"{}\" font-size=\"16\" font-weight=\"600\" fill=\"{}\">{}</text>",
+                top + PADDING + 16.0,
+                theme.foreground(),
+                theme.name()
+            );
+
+            for (label, (x, y, width)) in labels.iter().zip(&positions) {
+                let style = Style::new(*label.color(), theme);
+                let y = top + PADDING + TITLE_HEIGHT + y;
+
+                svg.push_str("<g>\n");
+
+                if let Some(description) = label.description() {
+                    let _ = writeln!(svg, "<title>{}</title>", escape(description.get()));
+                }
+
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{LABEL_HEIGHT}\" rx=\"{}\" fill=\"{}\" fill-opacity=\"{}\" stroke=\"{}\" stroke-opacity=\"{}\"/>",
+                    LABEL_HEIGHT / 2.0,
+                    style.background,
+                    style.background_opacity,
+                    style.border,
+                    style.border_opacity,
+                );
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"12\" font-weight=\"500\" text-anchor=\"middle\" fill=\"{}\">{}</text>",
+                    x + width / 2.0,
+                    y + 14.0,
+                    style.text,
+                    escape(label.name().get()),
+                );
+
+                svg.push_str("</g>\n");
+            }
+        }
+
+        svg.push_str("</svg>\n");
+
+        Ok(svg)
+    }
+}
+
+/// Arrange the labels in rows
+///
+/// Returns the position of each label relative to the first row as `(x, y, width)`, and the number
+/// of rows.
+fn layout(labels: &[Label]) -> (Vec<(f64, f64, f64)>, usize) {
+    let mut positions = Vec::with_capacity(labels.len());
+    let mut x = PADDING;
+    let mut row = 0;
+
+    for label in labels {
+        let width = (label.name().get().chars().count() as f64 * CHARACTER_WIDTH + 16.0).ceil();
+
+        if x > PADDING && x + width > WIDTH - PADDING {
+            x = PADDING;
+            row += 1;
+        }
+
+        positions.push((x, row as f64 * (LABEL_HEIGHT + GAP), width));
+        x += width + GAP;
+    }
+
+    let rows = if labels.is_empty() { 0 } else { row + 1 };
+
+    (positions, rows)
+}
+
+/// Returns the height of the given number of rows
+fn rows_height(rows: usize) -> f64 {
+    if rows == 0 {
+        return 0.0;
+    }
+
+    rows as f64 * LABEL_HEIGHT + (rows - 1) as f64 * GAP
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::color::Color;
+
+    use super::*;
+
+    fn label(name: &str) -> Label {
+        Label::builder()
+            .name(name)
+            .color(Color::from_hex(0xb91c1c))
+            .build()
+    }
+
+    #[test]
+    fn layout_wraps_rows() {
+        let labels: Vec<Label> = (0..20).map(|i| label(&format!("label-{i:02}"))).collect();
+
+        let (positions, rows) = layout(&labels);
+
+        assert_eq!(rows, 3);
+        assert_eq!(positions[0], (PADDING, 0.0, 72.0));
+        assert!(
+            positions
+                .iter()
+                .all(|(x, _, width)| x + width <= WIDTH - PADDING)
+        );
+    }
+
+    #[test]
+    fn layout_without_labels() {
+        assert_eq!(layout(&[]), (Vec::new(), 0));
+        assert_eq!(rows_height(0), 0.0);
+    }
+
+    #[test]
+    fn trait_write() {
+        let labels = vec![label("C-bug")];
+
+        let output = Svg.write(&labels).unwrap();
+
+        assert!(
+            output.starts_with(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"200\""
+            )
+        );
+        assert!(output.contains(">Light</text>"));
+        assert!(output.contains(">Dark</text>"));
+        assert!(output.contains("fill=\"#ffffff\">C-bug</text>"));
+        assert!(output.contains("fill=\"#b91c1c\" fill-opacity=\"0.18\""));
+        assert!(output.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn trait_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Svg>();
+    }
+
+    #[test]
+    fn trait_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<Svg>();
+    }
+
+    #[test]
+    fn trait_unpin() {
+        fn assert_unpin<T: Unpin>() {}
+        assert_unpin::<Svg>();
+    }
+}